@@ -0,0 +1,44 @@
+//! Map-like collections for the Tri-Entry form, which looks up a key
+//! and inserts a fallback value when it's missing.
+
+#[cfg(feature = "alloc")]
+use crate::__alloc::collections::BTreeMap;
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::{BuildHasher, Hash};
+
+/// A collection that can insert a value under a missing key and
+/// hand back a mutable reference to whatever is stored there.
+pub trait OrInsert {
+    /// The key type of the collection.
+    type Key;
+    
+    /// The value type of the collection.
+    type Value;
+    
+    /// Returns the value stored under `key`, inserting the output of
+    /// `fallback` first if the key is missing.
+    fn or_insert_with<F: FnOnce() -> Self::Value>(&mut self, key: Self::Key, fallback: F) -> &mut Self::Value;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, V> OrInsert for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+    
+    fn or_insert_with<F: FnOnce() -> V>(&mut self, key: K, fallback: F) -> &mut V {
+        self.entry(key).or_insert_with(fallback)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, V, S: BuildHasher> OrInsert for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+    
+    fn or_insert_with<F: FnOnce() -> V>(&mut self, key: K, fallback: F) -> &mut V {
+        self.entry(key).or_insert_with(fallback)
+    }
+}
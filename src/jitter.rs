@@ -0,0 +1,15 @@
+//! Delays for the jittered Tri-Until, which is enabled by the `rand`
+//! feature.
+
+use core::time::Duration;
+
+use rand::Rng;
+
+/// Returns the delay before the next attempt. The `base` delay in
+/// milliseconds is multiplied by `factor` once for every earlier
+/// attempt, and then by a random jitter between one half and three
+/// halves.
+pub fn delay<R: Rng + ?Sized>(rng: &mut R, base: u64, factor: u64, attempt: u32) -> Duration {
+    let scaled = base.saturating_mul(factor.saturating_pow(attempt));
+    Duration::from_millis(scaled).mul_f64(rng.random_range(0.5..1.5))
+}
@@ -4,6 +4,7 @@
     unused_imports,
     unused_mut,
 )]
+#![cfg_attr(all(test, feature = "nightly"), feature(box_patterns, coroutines, coroutine_trait, stmt_expr_attributes, try_blocks))]
 //!  This crate offers the `tri!` macro, a tool for concisely writing
 //!  tedious **try-except** statements.
 //!
@@ -11,11 +12,13 @@
 //!  common task. Although the `?` operator can be useful, it
 //!  forwards exceptions rather than handling them.
 //!
-//!     // Try Formats
-//!     tri!(a => b $$ c);
-//!     tri!(a => b(A) $$ c);
-//!     tri!(a => b[B] $$ c);
-//!     tri!(a => [R] $$ c);
+//! ```text
+//! // Try Formats
+//! tri!(a => b $$ c);
+//! tri!(a => b(A) $$ c);
+//! tri!(a => b[B] $$ c);
+//! tri!(a => [R] $$ c);
+//! ```
 //!
 //!  * `$$` - A Tri Operator
 //!  * `a` - The Expression to Evaluate
@@ -52,10 +55,13 @@
 //!
 //! `R` is for matching non-enum values to patterns. **..foo**,
 //! **_**, and **(FOO, 0..=bar)** are all acceptable patterns.
+//!  Const generic bounds such as **0..N** are checked with
+//!  `contains`, since they can't be used in a pattern.
 //!
 //!  # Tri Expressions
 //!
-//!  `tri!` has five operators for handling exceptions.
+//!  `tri!` has five core operators for handling exceptions. The
+//!  full operator list is in the [`tri!`](crate::tri) docs.
 //!  - Tri-Fall
 //!  - Tri-Fail
 //!  - Tri-Return
@@ -160,6 +166,48 @@
 #[macro_use]
 mod triage;
 
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub extern crate alloc as __alloc;
+
+#[doc(hidden)]
+#[cfg(feature = "futures")]
+pub extern crate futures as __futures;
+
+#[doc(hidden)]
+#[cfg(feature = "http")]
+pub extern crate http as __http;
+
+#[doc(hidden)]
+#[cfg(feature = "log")]
+pub extern crate log as __log;
+
+#[doc(hidden)]
+#[cfg(feature = "serde_json")]
+pub extern crate serde_json as __serde_json;
+
+#[doc(hidden)]
+#[cfg(feature = "tonic")]
+pub extern crate tonic as __tonic;
+
+#[doc(hidden)]
+#[cfg(feature = "tracing")]
+pub extern crate tracing as __tracing;
+
+pub mod entry;
+
+#[cfg(feature = "alloc")]
+pub mod located;
+
+#[cfg(feature = "rand")]
+pub mod jitter;
+
+#[cfg(feature = "traits")]
+pub mod traits;
+
+#[doc(hidden)]
+pub mod term;
+
 #[doc(hidden)]
 #[cfg(test)]
 mod tests;
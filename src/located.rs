@@ -0,0 +1,32 @@
+//! The error type for the located Tri-Fail, which records where in the
+//! source an expression failed.
+
+use core::fmt;
+
+use crate::__alloc::string::String;
+
+/// An error message paired with the `file:line` it was raised from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocatedError {
+    /// The source location, written as `file:line`.
+    pub location: &'static str,
+    
+    /// The message given to the operator.
+    pub message: String,
+}
+
+impl LocatedError {
+    /// Creates an error from a location and a message.
+    pub fn new(location: &'static str, message: impl Into<String>) -> Self {
+        Self { location, message: message.into() }
+    }
+}
+
+impl fmt::Display for LocatedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.location, self.message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LocatedError {}
@@ -0,0 +1,28 @@
+//! The term check for the logged Tri-Fail, which can only take the
+//! error out of a `Result`.
+
+/// A leading expression that `->log` can take an error out of.
+#[diagnostic::on_unimplemented(
+    message = "the `->log` operator needs a `Result` to match, but found `{Self}`",
+    label = "not a `Result`",
+    note = "`-> log` is read as the `->log` operator, so write `-> (log)` to return a value named `log`"
+)]
+pub trait LogTerm {
+    /// The error type of the term.
+    type Error;
+    
+    /// Returns the error held by a term that missed. An `Ok` value
+    /// the term didn't match has no error to return, so it panics.
+    fn into_error(self) -> Self::Error;
+}
+
+impl<T, E> LogTerm for Result<T, E> {
+    type Error = E;
+    
+    fn into_error(self) -> E {
+        match self {
+            Err(error) => error,
+            Ok(_) => panic!("the term of `->log` must match every `Ok` value"),
+        }
+    }
+}
@@ -1,3 +1,32 @@
+#![allow(clippy::no_effect, clippy::unused_unit)]
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::future::Future;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::num::{ParseIntError, Saturating, Wrapping};
+use std::pin::{pin, Pin};
+use std::ptr::NonNull;
+use std::rc::{Rc, Weak};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "std")]
+use std::process::ExitCode;
+
+#[cfg(feature = "alloc")]
+use crate::located::LocatedError;
+#[cfg(feature = "traits")]
+use crate::traits::TriExt;
+
+macro_rules! digit {
+    () => { 0..=9 };
+}
+
+macro_rules! both {
+    ($a:ident, $b:ident) => { Some(($a, $b)) };
+}
+
 #[test]
 fn tri_fall() {
     // Tri-Fall
@@ -17,6 +46,18 @@ fn tri_fall() {
     }
 }
 
+#[test]
+#[cfg(feature = "nightly")]
+fn tri_fall_box() {
+    let item = Some(Box::new(5_u8));
+    
+    // Tri-Fall (Box)
+    let value = tri!(item => Some(box value) <> 0);
+    tri!(None::<Box<u8>> => Some[box other] <> 0);
+    
+    assert_eq!((value, other), (5, 0));
+}
+
 #[test]
 fn tri_until() {
     let mut number: u8 = 0;
@@ -31,6 +72,16 @@ fn tri_until() {
     println!("Current Value: {number}\n");
 }
 
+#[test]
+fn tri_until_fold() {
+    let mut numbers = [1, 2, 3, 4].into_iter();
+    let mut total: u8 = 0;
+    
+    // Tri-Until (Fold)
+    tri!(numbers.next().map(|x| total += x) => None %> ());
+    assert_eq!(total, 10);
+}
+
 #[test]
 fn tri_while() {
     let mut number: u8 = 0;
@@ -58,24 +109,2460 @@ fn tri_while() {
     tri!(None::<()> => Some(_) >> println!("This Line Executed Once"));
 }
 
-fn tri_fail_and_return(item: Option<bool>) -> Result<&'static str, &'static str> {
-    tri!(item => Some(a @ true) -> "Item was either None or False.");
-    tri!(item => Some[var_name] #> Err("Item was None."));
+#[test]
+fn tri_map_err() {
+    // Tri-Map-Err
+    assert_eq!(read_config("8080"), Ok(8080));
+    assert_eq!(read_config("eighty"), Err(AppError::BadPort));
+    assert_eq!(read_pair(Ok((1, 2))), Ok(3));
+    assert_eq!(read_pair(Err(())), Err(AppError::Missing));
+}
+
+#[test]
+fn tri_fail_ip_addr() {
+    let v4 = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let v6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
     
-    if var_name { Ok("Item was True.") }
-    else { Err("Item was False.") }
+    assert_eq!(require_v4(v4), Ok(Ipv4Addr::LOCALHOST));
+    assert_eq!(require_v4(v6), Err("need IPv4"));
+    assert_eq!(require_v6(v6), Ok(Ipv6Addr::LOCALHOST));
+    assert_eq!(require_v6(v4), Err("need IPv6"));
 }
 
-fn print_person(name: &str, age: u8, height: (u8, u8)) {
-    println!("Name: {name}");
-    println!("Age: {age}");
-    println!("Height: {0}\'{1}\"", height.0, height.1);
-    println!();
+#[test]
+fn tri_bool() {
+    let mut numbers = [1, 2].into_iter();
+    let mut count = 0;
+    
+    // Tri-Bool
+    while tri!(numbers.next() => Some[_] bool>) { count += 1; }
+    assert_eq!(count, 2);
+    
+    if tri!(count => [1..=2] bool>) { count += 1; }
+    assert!(tri!(Some(count) => Some(3) bool>));
+    assert!(!tri!(Some(count) => None bool>));
 }
 
-fn number_mangler(item: Option<u8>) -> Option<u8> {
-    tri!(item => Some[var_name @ ..20] #> None);
-    Some(var_name + 1)
+#[test]
+#[deny(unused_variables)]
+fn tri_bindings_are_used() {
+    let item = Some(4_u8);
+    
+    tri!(item => Some[fall] <> 0);
+    let pair = tri!(item => Some(pair) <> 0);
+    
+    assert_eq!(fall + pair, 8);
+}
+
+#[test]
+fn tri_while_passes() {
+    let mut seen = Vec::new();
+    
+    // Tri-While (Caption)
+    tri!(step(value) => Some[value = 0] >> seen.push(value));
+    assert_eq!(seen, [0, 1, 2, 3]);
+    assert_eq!(value, 3);
+    
+    seen.clear();
+    tri!(step_pair(low, high) => Pair::Both[low = 0, high = 10] >> seen.push(high - low));
+    assert_eq!(seen, [10, 8, 6, 4, 2]);
+    assert_eq!((low, high), (4, 6));
+}
+
+#[test]
+fn tri_indexed_leading_expression() {
+    let grid = Grid { rows: vec![vec![1, 2], vec![3, 4, 5]] };
+    
+    assert_eq!(grid.cell(1, 2), &5);
+    assert_eq!(grid.cell(0, 2), &EMPTY);
+    
+    // Tri-Until (Indexed)
+    let mut column = 6;
+    let cell = tri!(grid.rows[1].get(column) => Some(cell) %> column -= 1);
+    assert_eq!((column, cell), (2, &5));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tri_exit() {
+    assert_eq!(run_cli(&["--port", "80"]), ExitCode::SUCCESS);
+    assert_eq!(run_cli(&["--port"]), ExitCode::from(2));
+    assert_eq!(run_cli(&["--port", "eighty"]), ExitCode::FAILURE);
+}
+
+#[test]
+fn tri_rule_const_bounds() {
+    assert_eq!(clamp_index::<4>(2), 2);
+    assert_eq!(clamp_index::<4>(9), 0);
+    assert_eq!(clamp_limit(6), 6);
+    assert_eq!(clamp_limit(LIMIT), 0);
+    assert_eq!(bucket::<3>(3), (true, false, true));
+    assert_eq!(bucket::<3>(5), (false, false, true));
+    assert_eq!(bucket::<3>(1), (true, true, false));
+    
+    let mut count = 0;
+    tri!(count => [LIMIT..] %> count += 3);
+    assert_eq!(count, 9);
+    
+    assert_eq!(checked_index::<4>(3), (Some(3), Ok(3)));
+    assert_eq!(checked_index::<4>(4), (None, Err(4)));
+}
+
+#[test]
+fn tri_flag() {
+    let mut had_error = false;
+    let mut total = 0;
+    
+    // Tri-Flag
+    for item in ["1", "x", "3"] {
+        tri!(item.parse::<u8>() => Ok[value] flag> had_error);
+        total += value;
+    }
+    
+    assert!(had_error);
+    assert_eq!(total, 4);
+}
+
+#[test]
+fn tri_iterator_leading_expression() {
+    let items = [Item { id: 1, weight: 4 }, Item { id: 2, weight: 9 }, Item { id: 3, weight: 1 }];
+    
+    assert_eq!(find_item(&items, 2).map(|item| item.weight), Ok(9));
+    assert_eq!(find_item(&items, 7).map(|item| item.weight), Err("not found"));
+    
+    let index = tri!(items.iter().position(|item| item.weight < 2) => Some(index) <> 0);
+    let heaviest = tri!(items.iter().max_by_key(|item| item.weight) => Some(item) <> &items[0]);
+    
+    assert_eq!(index, 2);
+    assert_eq!(heaviest.id, 2);
+}
+
+#[test]
+fn tri_rule_tuple_struct() {
+    assert_eq!(point_sum(Some(Point(2, 3))), 5);
+    assert_eq!(point_sum(None), 0);
+    
+    let mut total = 0;
+    for point in [Point(1, 1), Point(0, 4), Point(2, 5)] {
+        // Tri-Fall (Rule)
+        tri!(point => [Point(x @ 1.., y)] <> continue);
+        total += x * y;
+    }
+    assert_eq!(total, 11);
+}
+
+#[test]
+fn tri_option() {
+    // Tri-Option
+    assert_eq!(tri!("7".parse::<u8>() => Ok[value] opt>), Some(7));
+    assert_eq!(tri!("x".parse::<u8>() => Ok(value) opt>), None);
+    assert_eq!(tri!(Pair::Both(1, 2) => Pair::Both(a, b) opt>), Some((1, 2)));
+    assert_eq!(tri!(12 => [10..=20] opt>), Some(12));
+    assert_eq!(tri!(30 => [10..=20] opt>), None);
+    assert_eq!(double_port("21"), Some(42));
+}
+
+#[test]
+fn tri_fall_async() {
+    assert_eq!(block_on(cached_square(3)), 9);
+    assert_eq!(block_on(cached_square(5)), 25);
+}
+
+#[test]
+fn tri_ready() {
+    let mut context = Context::from_waker(Waker::noop());
+    let mut doubled = pin!(Doubled(Countdown { remaining: 1, output: Err("failed") }));
+    
+    // Tri-Ready
+    assert_eq!(doubled.as_mut().poll(&mut context), Poll::Pending);
+    assert_eq!(doubled.as_mut().poll(&mut context), Poll::Ready(Err("failed")));
+    
+    let doubled = pin!(Doubled(Countdown { remaining: 0, output: Ok(4) }));
+    assert_eq!(doubled.poll(&mut context), Poll::Ready(Ok(8)));
+}
+
+#[test]
+fn tri_slice_fields() {
+    let frame = Packet::Frame(7, [1, 2, 3, 4]);
+    
+    // Tri-Fall (Array Field)
+    tri!(frame => Packet::Frame[header, payload @ [1, ..]] <> (0, [0; 4]));
+    assert_eq!((header, payload), (7, [1, 2, 3, 4]));
+    
+    tri!(Packet::Frame(7, [5; 4]) => Packet::Frame[header, payload @ [1, ..]] <> (0, [0; 4]));
+    assert_eq!((header, payload), (0, [0; 4]));
+    
+    assert_eq!(split_chunk(Packet::Chunk(&[9, 8, 7])), Ok((9, &[8, 7][..])));
+    assert_eq!(split_chunk(Packet::Chunk(&[])), Err("empty chunk"));
+}
+
+#[test]
+fn tri_struct_caption() {
+    assert_eq!(read_line(Line::Entry("localhost", 80)), Ok(Config { host: "localhost", port: 80 }));
+    assert_eq!(read_line(Line::Comment), Err("bad line"));
+    
+    // Tri-Fall (Struct)
+    let config = tri!(Line::Comment => Line::Entry[host, port] <> Config::default() as Config);
+    assert_eq!(config, Config::default());
+    
+    // Tri-Option (Struct)
+    let sections: Vec<Option<Config>> = [Line::Entry("db", 5432), Line::Comment]
+        .into_iter()
+        .map(|line| tri!(line => Line::Entry[host, port] opt> as Config))
+        .collect();
+    assert_eq!(sections, [Some(Config { host: "db", port: 5432 }), None]);
+}
+
+#[test]
+fn tri_wide_fields() {
+    let wide = Wide::Row(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    
+    // Tri-Fall (Caption)
+    tri!(wide => Wide::Row[a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p] <> unreachable!());
+    assert_eq!(a + b + c + d + e + f + g + h + i + j + k + l + m + n + o + p, 136);
+    
+    // Tri-Fall (Variant)
+    let row = tri!(wide => Wide::Row(a, b, c, d, e, f, g, h, i, j, k, l, m, n, o, p) <> unreachable!());
+    assert_eq!(row.15, 16);
+    
+    // Tri-Fall (Mixed Batch)
+    tri!(wide => Wide::Row[a, b, 3, d, e, f, g @ 7, h, i, j, k, l, m, n, o, _] <> unreachable!());
+    assert_eq!(a + b + d + e + f + g + h + i + j + k + l + m + n + o, 117);
+    
+    tri!(Wide::Flag(1, true, 2, false) => Wide::Flag[a, true, b, false] <> (0, 0));
+    assert_eq!((a, b), (1, 2));
+}
+
+#[test]
+fn tri_until_retry() {
+    let (tx, rx) = mpsc::channel();
+    let sender = std::thread::spawn(move || {
+        for number in 1..=3 { tx.send(number).unwrap(); }
+    });
+    
+    assert_eq!(drain(&rx), Err("channel closed"));
+    sender.join().unwrap();
+    
+    let (tx, rx) = mpsc::channel();
+    tx.send(7).unwrap();
+    
+    assert_eq!(first_message(&rx), Some(7));
+    
+    drop(tx);
+    assert_eq!(first_message(&rx), None);
+}
+
+#[test]
+fn tri_field_fallback() {
+    // Field Fallback
+    let (a, b) = tri!(Pair::Both(3, 20) => Pair::Both(a @ 1..=9 <> 0, b @ 1..=9 <> 1));
+    assert_eq!((a, b), (3, 1));
+    
+    let (a, b) = tri!(Pair::Done => Pair::Both(a @ 1..=9 <> 0, b <> 1));
+    assert_eq!((a, b), (0, 1));
+    
+    let (a, b) = tri!(Pair::Both(4, 5) => Pair::Both(a <> 0, b <> 0));
+    assert_eq!((a, b), (4, 5));
+}
+
+#[test]
+fn tri_assert() {
+    // Tri-Assert
+    tri!(42 => [0..=100] assert>);
+    tri!(None::<u8> => None assert>);
+    tri!(Some(7) => Some[count @ 1..] assert>);
+    
+    let (host, port) = tri!(Line::Entry("localhost", 80) => Line::Entry(host, port) assert>);
+    assert_eq!((count, host, port), (7, "localhost", 80));
+}
+
+#[test]
+#[should_panic(expected = "value: Some(300)")]
+fn tri_assert_rejects() {
+    tri!(Some(300) => Some[_count @ ..=255] assert>);
+}
+
+#[test]
+fn tri_guard() {
+    // Tri-Fall (Guard)
+    tri!(Some(12) => Some[count] if count > 10 <> 0);
+    assert_eq!(count, 12);
+    
+    tri!(Some(8) => Some[count] if count > 10 <> 0);
+    assert_eq!(count, 0);
+    
+    let count = tri!(Some(16u32) => Some(count) if count.is_power_of_two() <> 1);
+    assert_eq!(count, 16);
+    
+    assert_eq!(shout(Some(String::from("ada"))), Ok(String::from("ADA")));
+    assert_eq!(shout(Some(String::from("Ada"))), Err("not lowercase"));
+    assert_eq!(shout(None), Err("not lowercase"));
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn tri_yield() {
+    use std::ops::{Coroutine, CoroutineState};
+    use std::cell::Cell;
+    
+    let slot = Cell::new(None);
+    let mut worker = #[coroutine] || {
+        // Tri-Yield
+        tri!(slot.take() => Some[job] yield> "stalled");
+        job * 2
+    };
+    
+    assert_eq!(Pin::new(&mut worker).resume(()), CoroutineState::Yielded("stalled"));
+    assert_eq!(Pin::new(&mut worker).resume(()), CoroutineState::Yielded("stalled"));
+    
+    slot.set(Some(21));
+    assert_eq!(Pin::new(&mut worker).resume(()), CoroutineState::Complete(42));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tri_each() {
+    assert_eq!(collect_all(vec![Some(1), Some(2), Some(3)]), Ok(vec![1, 2, 3]));
+    assert_eq!(collect_all(vec![Some(1), None, Some(3)]), Err("missing item"));
+    
+    // Tri-Each (Variant)
+    let pairs = tri!([Pair::Both(1, 2), Pair::Done] => each Pair::Both(a, b) <> (0, 0));
+    assert_eq!(pairs, vec![(1, 2), (0, 0)]);
+}
+
+#[test]
+fn tri_non_null() {
+    let mut value = 9u8;
+    let pointer = NonNull::from(&mut value);
+    
+    // Tri-Fall (NonNull)
+    tri!(Some(pointer) => Some[bound] <> NonNull::dangling());
+    assert_eq!(bound, pointer);
+    
+    assert_eq!(read_pointer(Some(pointer)), Ok(9));
+    assert_eq!(read_pointer(None), Err("null pointer"));
+}
+
+#[test]
+fn tri_tap() {
+    let mut seen = Vec::new();
+    
+    // Tri-Fall (Tap)
+    tri!(Some(4) => Some[count] tap(|count: &u8| seen.push(*count)) <> 0);
+    tri!(None => Some[other] tap(|other: &u8| seen.push(*other)) <> 0);
+    assert_eq!((count, other), (4, 0));
+    
+    let (a, b) = tri!(Pair::Both(1, 2) => Pair::Both(a, b) tap(|&(a, b): &(u8, u8)| seen.push(a + b)) <> (0, 0));
+    assert_eq!((a, b), (1, 2));
+    assert_eq!(seen, [4, 3]);
+}
+
+#[test]
+fn tri_std_wrappers() {
+    // Tri-Fall (Reverse)
+    tri!(Reverse(3) => Reverse[inner] <> 0);
+    assert_eq!(inner, 3);
+    
+    let mut heap = BinaryHeap::from([Reverse(5), Reverse(2), Reverse(8)]);
+    tri!(heap.pop() => Some[least] <> Reverse(0));
+    tri!(least => Reverse[least] <> 0);
+    assert_eq!(least, 2);
+    
+    // Tri-Fall (Wrapping)
+    tri!(Wrapping(u8::MAX) + Wrapping(2) => Wrapping[wrapped] <> 0);
+    assert_eq!(wrapped, 1);
+    
+    // Tri-Fail (Saturating)
+    let total = tri!(Saturating(250u8) + Saturating(10) => std::num::Saturating(total @ 0..=254) <> 0);
+    assert_eq!(total, 0);
+}
+
+#[test]
+fn tri_fall_break_total() {
+    assert_eq!(sum_prefix(&["1", "2", "3"]), 6);
+    assert_eq!(sum_prefix(&["4", "5", "x", "6"]), 9);
+    assert_eq!(sum_prefix(&["x"]), 0);
+    
+    let mut numbers = [7, 8, 0, 9].into_iter();
+    let mut total = 0;
+    
+    // Tri-Fall (Break Loop)
+    let partial = loop {
+        tri!(numbers.next() => Some[number @ 1..] <> break total);
+        total += number;
+    };
+    assert_eq!(partial, 15);
+}
+
+#[test]
+fn tri_at_subpattern() {
+    let spread = |pair: Option<(u8, u8)>| -> Result<(u8, u8, u8), &'static str> {
+        tri!(pair => Some[whole @ (low, high)] -> "missing pair");
+        Ok((whole.0 + whole.1, low, high))
+    };
+    
+    assert_eq!(spread(Some((2, 5))), Ok((7, 2, 5)));
+    assert_eq!(spread(None), Err("missing pair"));
+    
+    tri!(Some((3, 4)) => [Some(whole @ (low, high))] <> return);
+    assert_eq!((whole, low, high), ((3, 4), 3, 4));
+    
+    tri!(Some((1, 9)) => Some[whole @ (_, 5..)] <> (0, 0));
+    assert_eq!(whole, (1, 9));
+}
+
+#[test]
+fn tri_option_shorthand() {
+    // Tri-Fall (Some)
+    tri!(Some(3) => some value <> 0);
+    tri!(None => some other <> 4);
+    assert_eq!((value, other), (3, 4));
+    
+    tri!(Some(1) => some mut count <> 0);
+    count += 1;
+    assert_eq!(count, 2);
+    
+    assert_eq!(first_filled(&[None, Some(2)]), Some(1));
+    assert_eq!(first_filled(&[None, None]), None);
+    
+    // Tri-Fall (Type Named some)
+    tri!(some(5) => some[inner] <> 0);
+    assert_eq!(inner, 5);
+}
+
+#[test]
+fn tri_single_binding_is_scalar() {
+    // Tri-Fall
+    tri!(Some(1u8) => Some[fall] <> 0);
+    let _: u8 = fall;
+    
+    // Tri-Until
+    let mut polls = 0;
+    tri!(Some(2u8) => Some[until] %> polls += 1);
+    let _: u8 = until;
+    
+    // Tri-While
+    tri!(step(value) => Some[value = 0] >> polls += 1);
+    let _: u8 = value;
+    
+    // Tri-Fall (Variant)
+    let variant: u8 = tri!(Some(3u8) => Some(variant) <> 0);
+    
+    // Tri-Option
+    let option: Option<u8> = tri!(Some(4u8) => Some[option] opt>);
+    
+    assert_eq!((fall, until, variant, option, polls), (1, 2, 3, Some(4), 4));
+    assert_eq!(scalar_fail(Some(5)), Ok(5));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tri_emit() {
+    let (tx, rx) = mpsc::channel();
+    
+    assert_eq!(forward(&tx, "12"), Ok(()));
+    assert_eq!(forward(&tx, "x"), Err("process failed"));
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [24]);
+    
+    drop(rx);
+    assert_eq!(forward(&tx, "3"), Err("process failed"));
+}
+
+#[test]
+fn tri_raw_pattern() {
+    assert_eq!(raw_digit(4), Some(4));
+    assert_eq!(raw_digit(12), None);
+    
+    assert_eq!(raw_pair(Some((1, 2))), Ok(3));
+    assert_eq!(raw_pair(None), Err("missing pair"));
+}
+
+#[test]
+fn tri_convert() {
+    assert_eq!(load_count(Ok("7")), Ok(7));
+    assert!(matches!(load_count(Err(io::ErrorKind::NotFound.into())), Err(LoadError::Io(_))));
+    assert!(matches!(load_count(Ok("x")), Err(LoadError::Parse(_))));
+    assert!(matches!(load_count(Ok("0")), Err(LoadError::Empty)));
+}
+
+#[test]
+fn tri_ok_or() {
+    let lines = ["depth = 4", "width"];
+    
+    assert_eq!(load_setting(&lines, "depth"), Ok(4));
+    assert!(matches!(load_setting(&lines, "height"), Err(LoadError::Empty)));
+    assert!(matches!(load_setting(&lines, "width"), Err(LoadError::Io(_))));
+    
+    let check = |depth: Option<u8>| -> Result<u8, LoadError> {
+        let depth = tri!(depth => Some(depth) ok_or> LoadError::Empty);
+        tri!(depth => [1..] ok_or> LoadError::Empty);
+        tri!(Some(depth).filter(|depth| *depth > 9) => None ok_or> io::Error::from(io::ErrorKind::InvalidData));
+        Ok(depth)
+    };
+    assert_eq!(check(Some(4)), Ok(4));
+    assert!(matches!(check(None), Err(LoadError::Empty)));
+    assert!(matches!(check(Some(0)), Err(LoadError::Empty)));
+    assert!(matches!(check(Some(12)), Err(LoadError::Io(_))));
+}
+
+#[test]
+fn tri_until_tracked() {
+    let mut readings = [Err(1), Err(2), Ok(30), Ok(40)].into_iter();
+    
+    // Tri-Until (Tracked)
+    tri!(readings.next().unwrap() => Ok[value] @ (count, last) %> ());
+    assert_eq!((value, count, last), (30, 3, Some(Err(2))));
+    
+    tri!(readings.next().unwrap() => Ok[value] @ (count, last) %> ());
+    assert_eq!((value, count, last), (40, 1, None));
+}
+
+#[test]
+fn tri_field_guard() {
+    // Tri-Fall (Field Guard)
+    let (low, high) = tri!(Pair::Both(2, 7) => Pair::Both(low, high if high > low) <> (0, 0));
+    assert_eq!((low, high), (2, 7));
+    
+    let (low, high) = tri!(Pair::Both(7, 2) => Pair::Both(low, high @ 1.. if high > low) <> (0, 0));
+    assert_eq!((low, high), (0, 0));
+    
+    tri!(Pair::Both(3, 9) => Pair::Both[low if low % 3 == 0, high if high > low] if low > 0 <> (1, 1));
+    assert_eq!((low, high), (3, 9));
+    
+    tri!(Pair::Both(4, 9) => Pair::Both[low if low % 3 == 0, high] <> (1, 1));
+    assert_eq!((low, high), (1, 1));
+}
+
+#[test]
+#[cfg(feature = "rand")]
+fn tri_until_jitter() {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::time::Duration;
+    
+    let mut attempts = [Err(()), Err(()), Err(()), Ok(5)].into_iter();
+    let mut delays = Vec::new();
+    let mut rng = StdRng::seed_from_u64(7);
+    
+    // Tri-Until (Jitter)
+    tri!(attempts.next().unwrap() => Ok[value] %>jitter(&mut rng, 50, 2, |delay| delays.push(delay)));
+    assert_eq!(value, 5);
+    
+    let mut replay = StdRng::seed_from_u64(7);
+    let expected: Vec<Duration> = (0..3).map(|attempt| crate::jitter::delay(&mut replay, 50, 2, attempt)).collect();
+    assert_eq!(delays, expected);
+    
+    for (attempt, delay) in delays.iter().enumerate() {
+        let scaled = 50 * 2_u64.pow(attempt as u32);
+        assert!((scaled / 2..scaled * 3 / 2).contains(&(delay.as_millis() as u64)));
+    }
+    
+    let mut attempts = [Err(()), Ok(8)].into_iter();
+    let value = tri!(attempts.next().unwrap() => Ok(value) %>jitter(&mut rng, 1, 1, |_| ()));
+    assert_eq!(value, 8);
+}
+
+#[test]
+fn tri_ffi_codes() {
+    assert_eq!(check_code(Code::Ok), Ok(()));
+    assert_eq!(check_code(Code::Busy), Err("ffi error"));
+    
+    assert_eq!(check_raw(0), Ok(()));
+    assert_eq!(check_raw(Code::Busy as i32), Err("busy"));
+    assert_eq!(check_raw(-1), Err("nonzero"));
+}
+
+#[test]
+fn tri_loop() {
+    let mut items = [3, 1, 4, 1, 5].into_iter();
+    let mut total = 0;
+    
+    tri_loop!(items.next() => Some[item] { total += item });
+    assert_eq!(total, 14);
+    
+    let mut items = [1, 3, 5, 6, 7].into_iter();
+    let mut odds = Vec::new();
+    
+    // Stops At The First Item Failing The Guard
+    tri_loop!(items.next() => Some[item] if item % 2 == 1 { odds.push(item) });
+    assert_eq!(odds, [1, 3, 5]);
+    assert_eq!(items.next(), Some(7));
+    
+    let mut count = 0_u8;
+    tri_loop!(count => [0..=3] { count += 1 });
+    assert_eq!(count, 4);
+}
+
+#[test]
+fn tri_gather() {
+    let mut errors = Vec::new();
+    let total = parse_all(&["1", "x", "2", "", "3"], &mut errors);
+    
+    assert_eq!(total, 6);
+    assert_eq!(errors.len(), 2);
+    
+    let mut errors = Vec::new();
+    let mut values = Vec::new();
+    
+    for item in [Ok(2), Err("lost"), Ok(3), Ok(0), Err("late")] {
+        tri!(item => [Ok(1..)] gather> errors);
+        values.push(tri!(item => Ok(value) gather> errors));
+    }
+    
+    assert_eq!(values, [2, 3]);
+    assert_eq!(errors, ["lost", "late"]);
+}
+
+#[test]
+fn tri_typed_rule() {
+    // Without The Type, `leading_zeros` Is Called On An Ambiguous Integer
+    tri!(40 + 2 => [n: i64] <> 0);
+    assert_eq!(n.leading_zeros(), 58);
+    
+    tri!(n.into() => [mut wide: i128] <> 0);
+    wide <<= 64;
+    assert_eq!(wide.trailing_zeros(), 65);
+}
+
+#[test]
+fn tri_while_count() {
+    let mut items = [4, 8, 15, 16, 23, 42].into_iter();
+    let mut total = 0;
+    
+    // Tri-While (Count)
+    tri!(items.next() => Some[item] >>count processed, total += item);
+    assert_eq!((processed, total), (6, 108));
+    
+    let mut items = [Some(1), Some(2), None, Some(3)].into_iter();
+    tri!(items.next() => Some(Some(_)) >>count leading);
+    assert_eq!(leading, 2);
+    
+    let mut bytes = b"aab".iter();
+    tri!(bytes.next() => [Some(b'a')] >>count repeats);
+    assert_eq!(repeats, 2);
+    
+    let mut queue = [None, Some(1)].into_iter();
+    tri!(queue.next().flatten() => None >>count empty);
+    assert_eq!(empty, 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tri_fail_log() {
+    assert_eq!(open_port("8080"), Ok(8080));
+    assert_eq!(open_port("port"), Err("port".parse::<u16>().unwrap_err()));
+    
+    let parsed = |text: &str| -> Result<u16, ParseIntError> { Ok(tri!(text.parse::<u16>() => Ok(port) ->log) + 1) };
+    assert_eq!(parsed("1"), Ok(2));
+    assert!(parsed("").is_err());
+}
+
+#[test]
+fn tri_fail_log_local() {
+    let parsed = |item: Option<u8>| -> Result<u8, (u8, &str)> {
+        let log = (7, "missing");
+        
+        // Tri-Fail (Parenthesized Local)
+        tri!(item => Some[value] -> (log));
+        Ok(value)
+    };
+    
+    assert_eq!(parsed(Some(3)), Ok(3));
+    assert_eq!(parsed(None), Err((7, "missing")));
+}
+
+#[test]
+#[cfg(feature = "log")]
+fn tri_fail_log_record() {
+    use std::sync::Mutex;
+    
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    
+    struct Capture;
+    
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool { true }
+        fn log(&self, record: &log::Record) { RECORDS.lock().unwrap().push(format!("{} {}", record.level(), record.args())) }
+        fn flush(&self) {}
+    }
+    
+    log::set_logger(&Capture).unwrap();
+    log::set_max_level(log::LevelFilter::Error);
+    
+    assert!(open_port("-1").is_err());
+    assert_eq!(RECORDS.lock().unwrap().as_slice(), ["ERROR port.parse() failed: ParseIntError { kind: InvalidDigit }"]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tri_entry() {
+    use std::collections::{BTreeMap, HashMap};
+    
+    let mut lengths = HashMap::new();
+    let mut loads = 0;
+    
+    for word in ["tri", "ton", "tri"] {
+        tri!(lengths, word => length <> { loads += 1; word.len() });
+        *length += 1;
+    }
+    
+    assert_eq!(lengths["tri"], 5);
+    assert_eq!(loads, 2);
+    
+    let mut counts = BTreeMap::from([(2, 10)]);
+    
+    for key in [1, 2, 2] {
+        tri!(&mut counts, key => count <> 0);
+        *count += 1;
+    }
+    
+    assert_eq!(counts.into_iter().collect::<Vec<_>>(), [(1, 1), (2, 12)]);
+}
+
+#[test]
+fn tri_nested_caption() {
+    let nested = |depth: u8| match depth { 0 => None, 1 => Some(None), _ => Some(Some(depth)) };
+    
+    assert_eq!(tri!(nested(2) => Some(Some[value]) <> 0), 2);
+    assert_eq!(tri!(nested(1) => Some(Some[value]) <> 0), 0);
+    assert_eq!(tri!(nested(0) => Some(Some[value]) <> 0), 0);
+    
+    tri!(nested(3) => Some[Some[value]] <> 0);
+    assert_eq!(value, 3);
+    
+    tri!(nested(1) => flatten Some[value] <> 9);
+    assert_eq!(value, 9);
+    
+    tri!(Some(Pair::Both(5, 6)) => Some[Pair::Both[low, high]] <> (0, 0));
+    assert_eq!((low, high), (5, 6));
+    
+    tri!(Some(Pair::Done) => Some[Pair::Both[low, high]] <> (0, 0));
+    assert_eq!((low, high), (0, 0));
+}
+
+#[test]
+fn tri_nested_tuple_caption() {
+    let pair = |hit: bool| hit.then_some((3, 4));
+    
+    tri!(pair(true) => Some[(low, high)] <> (0, 0));
+    assert_eq!((low, high), (3, 4));
+    
+    tri!(pair(false) => Some[(low, high)] <> (0, 0));
+    assert_eq!((low, high), (0, 0));
+    
+    tri!(Ok::<_, u8>((1, 2, 5)) => Ok[(low, high, width)] <> (0, 0, 1));
+    assert_eq!((low, high, width), (1, 2, 5));
+    
+    assert_eq!(tri!(pair(true) => Some((low, high)) <> (0, 0)), (3, 4));
+    assert_eq!(tri!(Pair::Both(5, 6) => Pair::Both(low, high) <> (0, 0)), (5, 6));
+    
+    tri!(Some(Pair::Both(5, 6)) => Some[Pair::Both[low, high]] <> (0, 0));
+    assert_eq!((low, high), (5, 6));
+}
+
+#[test]
+fn tri_fall_else() {
+    let parse = |text: &str| tri!(text.parse::<i32>() => Ok(value) <else e> -(e.to_string().len() as i32));
+    assert_eq!(parse("12"), 12);
+    assert_eq!(parse("twelve"), -("invalid digit found in string".len() as i32));
+    
+    tri!(Err::<(u8, u8), u8>(7) => Ok[pair] <else code> (code, code + 1));
+    assert_eq!(pair, (7, 8));
+}
+
+#[test]
+fn tri_guard_continue() {
+    let readings = [Some(12), None, Some(-4), Some(30), Some(101), Some(7)];
+    let mut valid = Vec::new();
+    
+    for reading in readings {
+        tri!(reading => Some[value] if (0..=100).contains(&value) <> continue);
+        valid.push(value);
+    }
+    
+    assert_eq!(valid, [12, 30, 7]);
+    
+    let mut doubled = Vec::new();
+    
+    'outer: for row in [[Some(1), Some(2)], [None, Some(3)], [Some(4), Some(-5)]] {
+        for cell in row {
+            doubled.push(tri!(cell => Some(value) if value > 0 <> continue 'outer) * 2);
+        }
+    }
+    
+    assert_eq!(doubled, [2, 4, 8]);
+}
+
+#[test]
+fn tri_typed_caption() {
+    assert_eq!(parse_count("42"), Ok(42));
+    assert_eq!(parse_count("-1"), Err("not a number"));
+    
+    tri!("7".parse() => Ok[mut port: u16] <> 0);
+    port += u16::MAX - 7;
+    assert_eq!(port, u16::MAX);
+    
+    let (head, tail) = "3.5 2".split_once(' ').unwrap();
+    tri!(head.parse() => Ok[scale: f64] <> 1.0);
+    tri!(tail.parse() => Ok[count: std::num::NonZeroU8] <> std::num::NonZeroU8::MIN);
+    assert_eq!(scale * f64::from(count.get()), 7.0);
+    
+    tri!(head.split('.').next() => Some[whole: &'static str] <> "");
+    assert_eq!(whole, "3");
+    
+    fn parse_count(text: &str) -> Result<u32, &'static str> {
+        tri!(text.parse() => Ok[count: u32] -> "not a number");
+        Ok(count)
+    }
+}
+
+#[test]
+fn tri_into() {
+    assert_eq!(load_into(Some("4")), Ok(4));
+    assert!(matches!(load_into(None), Err(LoadError::Io(_))));
+    assert_eq!(load_into(Some("four")), Err(LoadError::Empty));
+    
+    let check = |code: i32| -> Result<(), Box<dyn std::error::Error>> {
+        tri!(code => [0 | 1] ~> "unknown code");
+        tri!(code => BUSY ~> String::from("not busy"));
+        Ok(())
+    };
+    
+    assert_eq!(check(0).unwrap_err().to_string(), "not busy");
+    assert_eq!(check(2).unwrap_err().to_string(), "unknown code");
+    assert!(check(1).is_ok());
+    
+    let double = |items: &[u8]| -> Result<u8, String> { Ok(tri!(items.first() => Some(first) ~> "empty") * 2) };
+    assert_eq!(double(&[3]), Ok(6));
+    assert_eq!(double(&[]), Err(String::from("empty")));
+}
+
+#[test]
+fn tri_bool_while() {
+    let mut items = [2, 4, 5, 6].into_iter();
+    let mut count = 0;
+    
+    while tri!(items.next() => Some[_] bool>) { count += 1 }
+    assert_eq!(count, 4);
+    
+    let mut items = [2, 4, 5, 6].into_iter();
+    let mut evens = 0;
+    
+    while tri!(items.next() => Some(2 | 4 | 6) bool>) { evens += 1 }
+    assert_eq!((evens, items.next()), (2, Some(6)));
+    
+    let mut value = 40_u8;
+    while tri!(value => [0..=42] bool>) { value += 1 }
+    assert_eq!(value, 43);
+}
+
+#[test]
+#[cfg(feature = "http")]
+#[allow(clippy::result_large_err)]
+fn tri_http() {
+    use http::{Response, StatusCode};
+    
+    let handle = |body: &str, id: u32| -> Result<String, Response<String>> {
+        tri!(body.parse::<u8>() => Ok[count] http> (StatusCode::BAD_REQUEST, "invalid body"));
+        tri!(id => [1..=99] http> (StatusCode::NOT_FOUND, format!("no item {id}")));
+        Ok(tri!(count.checked_mul(10) => Some(total) http> (StatusCode::UNPROCESSABLE_ENTITY, String::new())).to_string())
+    };
+    
+    assert_eq!(handle("4", 7).unwrap(), "40");
+    
+    let response = handle("four", 7).unwrap_err();
+    assert_eq!((response.status(), response.body().as_str()), (StatusCode::BAD_REQUEST, "invalid body"));
+    
+    let response = handle("4", 700).unwrap_err();
+    assert_eq!((response.status(), response.body().as_str()), (StatusCode::NOT_FOUND, "no item 700"));
+    
+    let response = handle("40", 7).unwrap_err();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[test]
+#[cfg(feature = "tonic")]
+fn tri_status() {
+    use tonic::{Code, Status};
+    
+    let handle = |name: Option<&str>, age: u8| -> Result<String, Status> {
+        tri!(name => Some[name] status> (Code::InvalidArgument, "name required"));
+        tri!(age => [18..] status> (Code::PermissionDenied, format!("{name} is too young")));
+        Ok(tri!(name.strip_prefix('@') => Some(name) status> (Code::NotFound, "unknown handle")).to_owned())
+    };
+    
+    assert_eq!(handle(Some("@tri"), 30).unwrap(), "tri");
+    
+    let status = handle(None, 30).unwrap_err();
+    assert_eq!((status.code(), status.message()), (Code::InvalidArgument, "name required"));
+    
+    let status = handle(Some("@ton"), 12).unwrap_err();
+    assert_eq!((status.code(), status.message()), (Code::PermissionDenied, "@ton is too young"));
+    
+    let status = handle(Some("ton"), 40).unwrap_err();
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[test]
+fn tri_until_value() {
+    let mut polls = [Poll::Pending, Poll::Ready(Pair::Done), Poll::Ready(Pair::Both(3, 4))].into_iter();
+    
+    // Tri-Until (No Fields)
+    let () = tri!(polls.next().unwrap() => Poll::Ready(_) %> ());
+    assert!(matches!(polls.next(), Some(Poll::Ready(Pair::Both(3, 4)))));
+    
+    let mut polls = [Poll::Pending, Poll::Ready(7)].into_iter();
+    
+    // Tri-Until (One Field)
+    let value = tri!(polls.next().unwrap() => Poll::Ready(value) %> ());
+    assert_eq!(value, 7);
+    
+    let mut pairs = [Pair::Done, Pair::Both(1, 2)].into_iter();
+    
+    // Tri-Until (Fields)
+    let (low, high) = tri!(pairs.next().unwrap() => Pair::Both(low, high) %> ());
+    assert_eq!((low, high), (1, 2));
+    
+    let mut pairs = [Pair::Done, Pair::Both(5, 6)].into_iter();
+    tri!(pairs.next().unwrap() => Pair::Both[low, high] %> ());
+    assert_eq!((low, high), (5, 6));
+    
+    let mut codes = [(1, 0), (0, 2), (0, 0)].into_iter();
+    tri!(codes.next().unwrap() => [0, 0] %> ());
+    assert_eq!(codes.next(), None);
+}
+
+#[test]
+fn tri_loop_label() {
+    let mut polls = [1, 2, 3].into_iter();
+    let mut spins = 0;
+    
+    // Tri-Until (Label)
+    tri!('wait: polls.next() => None %> {
+        spins += 1;
+        tri!(spins => [0..=1] #> break 'wait);
+    });
+    assert_eq!(polls.next(), Some(3));
+    
+    let mut budget = 3;
+    let mut queue = vec![1, 2, 3, 4, 5];
+    
+    // Tri-While (Label)
+    tri!('drain: queue.pop() => [Some(_)] >> {
+        tri!(budget => [1..] #> break 'drain);
+        budget -= 1;
+    });
+    assert_eq!(queue, [1, 2]);
+    
+    let mut count = 0;
+    tri!('count: count => [10] %> { count += 1; tri!(count => [0..=4] #> break 'count); });
+    assert_eq!(count, 5);
+}
+
+#[test]
+fn tri_until_range() {
+    let mut readings = [0, 140, 55, 80].into_iter();
+    let mut ticks = 0;
+    
+    // Tri-Until (Range)
+    tri!(readings.next() => Some[level @ 1..=100] %> ticks += 1);
+    assert_eq!((level, ticks), (55, 2));
+    
+    let level = tri!(readings.next() => Some(level @ 1..=100) %> ticks += 1);
+    assert_eq!((level, ticks), (80, 2));
+}
+
+#[test]
+#[cfg(feature = "futures")]
+fn tri_future() {
+    use futures::{future::Ready, FutureExt, TryFutureExt};
+    
+    fn respond(text: &str) -> Ready<Result<u16, &'static str>> {
+        // Tri-Future
+        tri!(text.parse::<u16>() => Ok[port] future> "not a port")
+    }
+    
+    let reserve = |port| tri!(port => [1024..] future> "reserved");
+    
+    assert_eq!(respond("8080").and_then(reserve).now_or_never(), Some(Ok(8080)));
+    assert_eq!(respond("80").and_then(reserve).now_or_never(), Some(Err("reserved")));
+    assert_eq!(respond("http").and_then(reserve).now_or_never(), Some(Err("not a port")));
+    
+    let doubled = tri!(Some(4) => Some(value) future> ()).map_ok(|value| value * 2);
+    assert_eq!(doubled.now_or_never(), Some(Ok(8)));
+    assert_eq!(tri!(None::<u8> => None future> 0).now_or_never(), Some(Ok(None)));
+}
+
+#[test]
+fn tri_until_capped() {
+    fn read_after(ready: u8, cap: usize) -> Result<(u8, u8), &'static str> {
+        let mut reads = 0;
+        let mut next = || { reads += 1; if reads > ready { Ok(reads) } else { Err(reads) } };
+        
+        // Tri-Until (Capped Fail)
+        tri!(next() => Ok[value] %[cap]> (), -> "gave up");
+        Ok((value, reads))
+    }
+    
+    assert_eq!(read_after(2, 16), Ok((3, 3)));
+    assert_eq!(read_after(2, 2), Ok((3, 3)));
+    assert_eq!(read_after(2, 1), Err("gave up"));
+    assert_eq!(read_after(0, 0), Ok((1, 1)));
+    assert_eq!(read_after(1, 0), Err("gave up"));
+    
+    let mut polls = 0;
+    let mut escaped = false;
+    
+    // Tri-Until (Capped Fall)
+    let value = tri!(None::<u8> => Some(value) %[3]> polls += 1, <> { escaped = true; 0 });
+    assert_eq!((value, polls, escaped), (0, 3, true));
+    
+    let mut polls = 0;
+    tri!(None::<u8> => Some[value] %[2]> polls += 1, <> 7);
+    assert_eq!((value, polls), (7, 2));
+    tri!(Some(4) => Some[value] %[2]> polls += 1, <> 7);
+    assert_eq!((value, polls), (4, 2));
+    tri!(Pair::Done => Pair::Both[low, high] %[1]> (), <> 8, 9,);
+    assert_eq!((low, high), (8, 9));
+    assert_eq!(tri!(Pair::Done => Pair::Both(low, high) %[1]> (), <> 1, 2), (1, 2));
+    
+    let mut fell = 0;
+    tri!(Pair::Both(1, 2) => Pair::Done %[1]> (), <> fell += 1);
+    tri!(Pair::Done => Pair::Done %[1]> (), <> fell += 10);
+    tri!(5 => [0..=3] %[2]> (), <> fell += 100);
+    tri!(2 => [0..=3] %[2]> (), <> fell += 1000);
+    assert_eq!(fell, 101);
+    
+    let mut queue = vec![Pair::Done, Pair::Both(1, 2), Pair::Both(3, 4)];
+    let mut drain = |cap: usize| -> Result<usize, ()> {
+        tri!(queue.pop().unwrap_or(Pair::Done) => Pair::Done %[cap]> (), -> ());
+        Ok(queue.len())
+    };
+    assert_eq!(drain(1), Err(()));
+    assert_eq!(drain(1), Ok(0));
+    
+    let mut reached = Vec::new();
+    for limit in [1, 5] {
+        let mut count = 0;
+        tri!(count => [5..] %[limit]> count += 2, <> continue);
+        reached.push((limit, count));
+    }
+    assert_eq!(reached, [(5, 6)]);
+    
+    let mut lines = ["", "", "ready"].into_iter();
+    let mut skipped = 0;
+    let mut escape = || -> Result<(), ()> {
+        tri!(lines.next() => Some("ready") %[0]> skipped += 1, -> ());
+        Ok(())
+    };
+    assert_eq!(escape(), Err(()));
+    assert_eq!(skipped, 0);
+}
+
+#[test]
+fn tri_panic() {
+    let table = [("port", 8080), ("workers", 4)];
+    let lookup = |key| table.iter().find(|(name, _)| *name == key).map(|(_, value)| *value);
+    
+    // Tri-Panic
+    tri!(lookup("port") => Some[port] !> "key must exist");
+    let workers = tri!(lookup("workers") => Some(workers) !>);
+    tri!(workers => [1..=8] !> "too many workers: {workers}");
+    tri!(Pair::Done => Pair::Done !>);
+    
+    assert_eq!((port, workers), (8080, 4));
+}
+
+#[test]
+#[should_panic(expected = "key must exist")]
+fn tri_panic_message() {
+    let table: [(&str, u16); 0] = [];
+    tri!(table.first() => Some[entry] !> "key must exist");
+    let _ = entry;
+}
+
+#[test]
+#[should_panic(expected = "too many workers: 12")]
+fn tri_panic_format() {
+    let workers = 12;
+    tri!(workers => [1..=8] !> "too many workers: {workers}");
+}
+
+#[test]
+#[should_panic(expected = "internal error: entered unreachable code")]
+fn tri_panic_unreachable() {
+    tri!(Pair::Both(1, 2) => Pair::Done !>);
+}
+
+#[test]
+fn tri_binding_modes() {
+    let default = String::from("guest");
+    let name = Some(String::from("ada"));
+    let missing: Option<String> = None;
+    let name_ref = &name;
+    
+    // Match Ergonomics (Caption)
+    tri!(name_ref => Some[user] <> &default);
+    let user: &String = user;
+    tri!(&missing => Some[other] <> &default);
+    let other: &String = other;
+    assert_eq!((user.as_str(), other.as_str()), ("ada", "guest"));
+    
+    // Match Ergonomics (Variant)
+    let user: &String = tri!(name_ref => Some(user) <> &default);
+    assert_eq!(user, "ada");
+    
+    let checked = || -> Result<usize, ()> {
+        tri!(name_ref => Some[user] -> ());
+        let user: &String = user;
+        tri!(name_ref => Some[user_len] if !user_len.is_empty() #> Err(()));
+        let user_len: &String = user_len;
+        tri!(name_ref => [Some(len)] #> Err(()));
+        let _: &String = len;
+        Ok(user.len() + user_len.len())
+    };
+    assert_eq!(checked(), Ok(6));
+    
+    tri!(name_ref => Some[user] assert>);
+    let user: &String = user;
+    tri!(name_ref => Some[same] !> "name must be set");
+    let same: &String = same;
+    assert!(core::ptr::eq(user, same));
+    assert_eq!(tri!(name_ref => Some(user) opt>).map(String::len), Some(3));
+    
+    let mut slot = Some(String::from("ada"));
+    let slot_mut = &mut slot;
+    
+    // Match Ergonomics (Mutable)
+    tri!(&mut *slot_mut => Some[user] <> unreachable!());
+    let user: &mut String = user;
+    user.push_str(" lovelace");
+    
+    let user: &mut String = tri!(&mut *slot_mut => Some(user) !>);
+    user.make_ascii_uppercase();
+    
+    let mut rounds = 0;
+    tri!(&mut *slot_mut => Some[user] %> rounds += 1);
+    let user: &mut String = user;
+    user.insert(0, '@');
+    
+    assert_eq!((slot.as_deref(), rounds), (Some("@ADA LOVELACE"), 0));
+    
+    let mut pair = (Some(1_u8), Some(2_u8));
+    tri!(&mut pair => [(Some(left), Some(right))] !>);
+    let (left, right): (&mut u8, &mut u8) = (left, right);
+    std::mem::swap(left, right);
+    assert_eq!(pair, (Some(2), Some(1)));
+    
+    let both = Pair::Both(3, 20);
+    let (a, b): (&u8, &u8) = tri!(&both => Pair::Both(a @ 1..=9 <> &0, b @ 1..=9 <> &1));
+    assert_eq!((a, b), (&3, &1));
+    
+    let nested = Some(Some(7_u8));
+    tri!(&nested => Some[Some[inner]] <> &0);
+    let inner: &u8 = inner;
+    
+    let readings = [None, Some(4_u8)];
+    let mut reading = readings.iter();
+    tri!(reading.next().unwrap() => Some[value] @ (count, last) %> ());
+    let (value, last): (&u8, Option<&Option<u8>>) = (value, last);
+    assert_eq!((inner, value, count, last), (&7, &4, 2, Some(&None)));
+    
+    let mut totals = [Some(1_u8), Some(2), None];
+    let mut slots = totals.iter_mut();
+    tri!(slots.next() => Some[Some[total]] >>count doubled, *total *= 10);
+    assert_eq!(doubled, 2);
+    assert_eq!(totals, [Some(10), Some(20), None]);
+}
+
+#[test]
+#[cfg(feature = "tracing")]
+fn tri_fail_span() {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+    use tracing::{field::Field, span, Event, Metadata, Subscriber};
+    
+    struct Capture(Arc<Mutex<Vec<String>>>);
+    
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata) -> bool { true }
+        fn new_span(&self, _: &span::Attributes) -> span::Id { span::Id::from_u64(1) }
+        fn record(&self, _: &span::Id, _: &span::Record) {}
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+        fn enter(&self, _: &span::Id) {}
+        fn exit(&self, _: &span::Id) {}
+        
+        fn event(&self, event: &Event) {
+            let mut line = event.metadata().level().to_string();
+            event.record(&mut |field: &Field, value: &dyn Debug| line += &format!(" {field}={value:?}"));
+            self.0.lock().unwrap().push(line);
+        }
+    }
+    
+    fn field<'a>(form: &[(&str, &'a str)], name: &str) -> Result<&'a str, String> {
+        let found = form.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+        
+        // Tri-Fail (Span)
+        tri!(found => Some[value] ->span("missing field", field = name, fields = form.len()));
+        tri!(value.len() => [1..] -> String::from("empty field"));
+        Ok(value)
+    }
+    
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let form = [("name", "tri"), ("port", "")];
+    
+    tracing::subscriber::with_default(Capture(events.clone()), || {
+        assert_eq!(field(&form, "name"), Ok("tri"));
+        assert_eq!(field(&form, "user"), Err(String::from("missing field")));
+        assert_eq!(field(&form, "port"), Err(String::from("empty field")));
+    });
+    
+    assert_eq!(events.lock().unwrap().as_slice(), ["ERROR message=missing field field=\"user\" fields=2"]);
+}
+
+#[test]
+fn tri_return_continue() {
+    let items = [Some(1), None, Some(3), None];
+    let mut seen = Vec::new();
+    
+    for item in items {
+        // Tri-Return (Continue)
+        let value = tri!(item => Some(value) #> continue);
+        seen.push(value);
+    }
+    
+    for item in items {
+        tri!(item => Some[value] #> continue);
+        seen.push(value * 10);
+    }
+    
+    assert_eq!(seen, [1, 3, 10, 30]);
+    
+    let mut pairs = Vec::new();
+    
+    'outer: for row in [[Some(1), Some(2)], [None, Some(4)], [Some(5), Some(6)]] {
+        for cell in row {
+            tri!(cell => [Some(value)] #> continue 'outer);
+            pairs.push(value);
+        }
+    }
+    
+    let mut done = 0;
+    for pair in [Pair::Done, Pair::Both(1, 2), Pair::Done] {
+        tri!(pair => Pair::Done #> continue);
+        done += 1;
+    }
+    
+    assert_eq!((pairs, done), (vec![1, 2, 5, 6], 2));
+}
+
+#[test]
+fn tri_default() {
+    let ports = std::collections::HashMap::from([("http", 80_u16)]);
+    
+    // Tri-Default
+    tri!(ports.get("http").copied() => Some[port] ?:);
+    assert_eq!(port, 80);
+    tri!(ports.get("ftp").copied() => Some[port] ?:);
+    assert_eq!(port, 0);
+    
+    let name: String = tri!(None::<String> => Some(name) ?:);
+    assert_eq!(name, "");
+    assert_eq!(tri!("x".parse::<u8>() => Ok(value) ?:), 0);
+    
+    tri!(Pair::Both(3, 4) => Pair::Both[low, high] ?:);
+    assert_eq!((low, high), (3, 4));
+    tri!(Pair::Done => Pair::Both[mut low, high] ?:);
+    low += 1;
+    assert_eq!((low, high), (1, 0));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn tri_env() {
+    use std::env::{self, VarError};
+    
+    env::set_var("TRI_TON_TEST_PORT", "9090");
+    env::remove_var("TRI_TON_TEST_UNSET");
+    
+    let port = || -> Result<String, &'static str> {
+        tri!(env::var("TRI_TON_TEST_PORT") => Ok[port] -> "PORT not set");
+        let port: String = port;
+        Ok(port)
+    };
+    let unset = || -> Result<String, &'static str> {
+        tri!(env::var("TRI_TON_TEST_UNSET") => Ok[port] -> "PORT not set");
+        Ok(port)
+    };
+    assert_eq!((port(), unset()), (Ok(String::from("9090")), Err("PORT not set")));
+    
+    // Env
+    tri!(env "TRI_TON_TEST_PORT" => Ok[port] <> "8080".into());
+    let port: String = port;
+    tri!(env "TRI_TON_TEST_UNSET" => Ok[fallback] <> "8080".into());
+    assert_eq!((port.as_str(), fallback.as_str()), ("9090", "8080"));
+    
+    assert!(tri!(env "TRI_TON_TEST_UNSET" => Err(VarError::NotPresent) bool>));
+    assert_eq!(tri!(env "TRI_TON_TEST_PORT" => Ok(port) opt>), Some(String::from("9090")));
+}
+
+#[test]
+fn tri_cache() {
+    struct Rates {
+        cached: Vec<u32>,
+        calls: u8,
+    }
+    
+    impl Rates {
+        fn recompute(&mut self) -> Result<Vec<u32>, &'static str> {
+            self.calls += 1;
+            match self.calls { 2 => Err("offline"), calls => Ok(vec![u32::from(calls) * 10]) }
+        }
+        
+        fn current(&mut self) -> Vec<u32> {
+            // Tri-Cache
+            tri!(self.recompute() => Ok[rates] cache> self.cached);
+            rates
+        }
+    }
+    
+    let mut rates = Rates { cached: Vec::new(), calls: 0 };
+    assert_eq!(rates.current(), [10]);
+    assert_eq!(rates.current(), [10]);
+    assert_eq!(rates.cached, [10]);
+    assert_eq!(rates.current(), [30]);
+    assert_eq!(rates.cached, [30]);
+    
+    let mut last = (0, 0);
+    assert_eq!(tri!(Pair::Both(1, 2) => Pair::Both(low, high) cache> last), (1, 2));
+    assert_eq!(tri!(Pair::Done => Pair::Both(low, high) cache> last), (1, 2));
+    assert_eq!(tri!(Pair::Both(3, 4) => Pair::Both(low, high) cache> last), (3, 4));
+    assert_eq!(last, (3, 4));
+    
+    let mut offline = Rates { cached: vec![7], calls: 1 };
+    assert_eq!(tri!(offline.recompute() => Ok(rates) <> offline.cached.clone()), [7]);
+}
+
+#[test]
+fn tri_struct_variant() {
+    let moves = [
+        Message::Move { x: 3, y: 4, speed: 2 },
+        Message::Quit,
+        Message::Move { x: -1, y: 9, speed: 0 },
+    ];
+    
+    // Struct Variants
+    let (x, y, speed) = tri!(moves[0] => Message::Move { x, y, speed } <> (0, 0, 0));
+    assert_eq!((x, y, speed), (3, 4, 2));
+    assert_eq!(tri!(moves[1] => Message::Move { x, y, .. } <> (0, 0)), (0, 0));
+    
+    let (left, top) = tri!(moves[2] => Message::Move { x: left, y: top, .. } <> (0, 0));
+    assert_eq!((left, top), (-1, 9));
+    
+    let moving: Vec<_> = moves.iter().map(|message| tri!(message => Message::Move { speed: 1.., x, .. } opt>)).collect();
+    assert_eq!(moving, [Some(&3), None, None]);
+    
+    let step = |message: Message| -> Result<i32, &'static str> {
+        let (mut x, y) = tri!(message => Message::Move { x, y, .. } -> "not a move");
+        x += y;
+        Ok(x)
+    };
+    assert_eq!((step(Message::Move { x: 1, y: 2, speed: 0 }), step(Message::Quit)), (Ok(3), Err("not a move")));
+    
+    assert!(tri!(moves[1] => Message::Quit {} bool>));
+    assert!(tri!(moves[0] => Message::Move { speed: 2, .. } bool>));
+    tri!(moves[2] => Message::Move { .. } !> "expected a move");
+}
+
+#[test]
+fn tri_transition_table() {
+    #[derive(Clone, Copy, Debug)]
+    enum Door { Open, Closed, Locked }
+    
+    fn transition(from: Door, to: Door) -> Result<Door, &'static str> {
+        use Door::*;
+        
+        // Rule (Or-Pattern)
+        tri!((from, to) => [(Open, Closed) | (Closed, Open | Locked) | (Locked, Closed)] -> "invalid transition");
+        Ok(to)
+    }
+    
+    let mut door = Door::Open;
+    for next in [Door::Closed, Door::Locked, Door::Closed, Door::Open] {
+        door = transition(door, next).unwrap();
+    }
+    
+    assert!(matches!(door, Door::Open));
+    assert_eq!(transition(Door::Open, Door::Locked).unwrap_err(), "invalid transition");
+    assert_eq!(transition(Door::Locked, Door::Open).unwrap_err(), "invalid transition");
+    assert!(!tri!((Door::Open, Door::Open) => [(Door::Open, Door::Closed) | (Door::Closed, Door::Open)] bool>));
+    assert!(tri!((door, Door::Closed) => [(Door::Open, Door::Closed) | (Door::Closed, Door::Open)] opt>).is_some());
+    
+    let mut rejected = 0;
+    for pair in [(Door::Locked, Door::Open), (Door::Open, Door::Closed)] {
+        tri!(pair => [(Door::Open, Door::Closed) | (Door::Locked, Door::Closed)] <> rejected += 1);
+    }
+    assert_eq!(rejected, 1);
+    
+    let mut states = [Door::Closed, Door::Locked, Door::Open].into_iter();
+    tri!(states.next() => [Some(Door::Open | Door::Locked)] %[4]> (), <> unreachable!());
+    assert!(matches!(states.next(), Some(Door::Open)));
+}
+
+#[test]
+fn tri_or_patterns() {
+    let mut states = [State::Loading, State::Pending, State::Loading, State::Ready(7)].into_iter();
+    let mut polls = 0;
+    
+    let ready = tri!(states.next().unwrap() => State::Ready(value) %> polls += 1);
+    assert_eq!((ready, polls), (7, 3));
+    
+    // Rule (Or-Pattern)
+    let mut states = [State::Loading, State::Pending, State::Failed].into_iter();
+    tri!(states.next().unwrap() => [State::Ready(_) | State::Failed] %> polls += 1);
+    assert_eq!(polls, 5);
+    
+    let state = State::Pending;
+    assert!(tri!(state => [State::Loading | State::Pending] bool>));
+    assert!(!tri!(State::Ready(1) => [State::Loading | State::Pending] bool>));
+    
+    // Path (Or-Pattern)
+    let mut states = [State::Ready(1), State::Failed, State::Pending].into_iter();
+    tri!(states.next().unwrap() => State::Loading | State::Pending %> polls += 1);
+    assert_eq!(polls, 7);
+    assert!(tri!(State::Failed => State::Loading | self::State::Failed bool>));
+    
+    let check = |pair: (Option<u8>, Result<u8, u8>)| -> Result<(), &'static str> {
+        tri!(pair => [Some(1 | 2), Ok(_) | Err(0)] -> "unexpected");
+        Ok(())
+    };
+    assert_eq!(check((Some(2), Err(0))), Ok(()));
+    assert_eq!(check((Some(1), Ok(9))), Ok(()));
+    assert_eq!(check((Some(3), Ok(9))), Err("unexpected"));
+    assert_eq!(check((Some(1), Err(4))), Err("unexpected"));
+    
+    tri!((Some(4), 5) => [Some(low @ (0..=4 | 9)), high] <> return);
+    assert_eq!((low, high), (4, 5));
+}
+
+#[test]
+fn tri_single_rule() {
+    let range = |value: u8| -> Result<u8, &'static str> {
+        // Rule (Single)
+        tri!(value => [0..=9] -> "out of range");
+        Ok(value)
+    };
+    assert_eq!((range(7), range(12)), (Ok(7), Err("out of range")));
+    
+    // A lone rule matches the whole value, so a binding takes the tuple.
+    tri!((1, Some(2)) => [pair @ (_, Some(_))] <> return);
+    assert_eq!(pair, (1, Some(2)));
+    
+    // Several rules destructure a tuple, one element each.
+    tri!((1, Some(2)) => [first, Some(second)] <> return);
+    assert_eq!((first, second), (1, 2));
+    
+    tri!((3, Some(4)) => [(low, Some(high))] <> return);
+    assert_eq!((low, high), (3, 4));
+    
+    assert!(tri!(Some(5) => [Some(1..=5)] bool>));
+    assert_eq!(tri!((6, 7) => [(6, _)] opt>), Some((6, 7)));
+    assert_eq!(tri!((6, 7) => [6, 7 | 8] opt>), Some((6, 7)));
+}
+
+#[test]
+fn tri_rule_guard() {
+    let big = |n: Option<u8>| -> Result<u8, &'static str> {
+        // Tri-Fail (Guard)
+        tri!(n => [Some(v)] if v > 10 -> "too small");
+        Ok(v)
+    };
+    assert_eq!((big(Some(12)), big(Some(3)), big(None)), (Ok(12), Err("too small"), Err("too small")));
+    
+    // The variant matches, but a failed guard still takes the fallback.
+    tri!(Some(3) => Some[v] if v > 10 <> 0);
+    assert_eq!(v, 0);
+    assert_eq!(tri!(Some(3) => Some(v) if v > 10 <> 0), 0);
+    assert_eq!(tri!(Some(12) => Some(v) if v > 10 <> 0), 12);
+    
+    let mut kept = Vec::new();
+    for pair in [(1, Some(5)), (2, None), (3, Some(1)), (4, Some(9))] {
+        tri!(pair => [index, Some(weight)] if weight > 2 <> continue);
+        kept.push((index, weight));
+    }
+    assert_eq!(kept, [(1, 5), (4, 9)]);
+    
+    let mut small = 0;
+    for weight in [Some(1), Some(7), None] {
+        tri!(weight => [Some(w)] if w < 5 <> small += 10);
+    }
+    assert_eq!(small, 20);
+    
+    let first_odd = |items: &[u8]| -> u8 {
+        for &item in items {
+            tri!(item => [n] if n % 2 == 0 #> item);
+        }
+        0
+    };
+    assert_eq!((first_odd(&[2, 4, 5]), first_odd(&[2])), (5, 0));
+}
+
+#[test]
+#[cfg(feature = "nightly")]
+fn tri_try_block() {
+    let sum = |a: &str, b: &str| {
+        // Try Block
+        tri!(try { a.parse::<u8>()? + b.parse::<u8>()? } => Ok(total) <> 0)
+    };
+    
+    assert_eq!((sum("4", "5"), sum("4", "five")), (9, 0));
+    
+    tri!(try { "12".parse::<u8>()? * 2 } => Ok[doubled] <> 0);
+    assert_eq!(doubled, 24);
+}
+
+#[test]
+fn tri_fall_retries() {
+    let mut calls = 0;
+    let mut attempt = |succeed_on: u8| { calls += 1; if calls == succeed_on { Ok(calls * 10) } else { Err(calls) } };
+    
+    // Tri-Fall (Retries)
+    tri!(attempt(9) => Ok[value] %[3]<> 7);
+    assert_eq!((value, calls), (7, 4));
+    
+    let mut calls = 0;
+    let mut attempt = || { calls += 1; Err::<u8, u8>(calls) };
+    tri!(attempt() => Ok[value] %[0]<> 7);
+    assert_eq!((value, calls), (7, 1));
+    
+    const TRIES: usize = 2;
+    let mut calls = 0;
+    let mut attempt = || { calls += 1; Err::<u8, u8>(calls) };
+    assert_eq!(tri!(attempt() => Ok(value) %[TRIES]<> 5), 5);
+    assert_eq!(calls, 3);
+    
+    let mut calls = 0;
+    let mut attempt = || { calls += 1; if calls == 2 { Ok(calls) } else { Err(()) } };
+    assert_eq!(tri!(attempt() => Ok(value) %[3]<> 0), 2);
+    assert_eq!(calls, 2);
+    assert_eq!(tri!(None::<u8> => Some(value) %[0]<> 5), 5);
+    
+    let mut reads = [Pair::Done, Pair::Done, Pair::Both(1, 2)].into_iter();
+    tri!(reads.next().unwrap() => Pair::Both[low, high] %[1]<> 0, 0);
+    assert_eq!((low, high, reads.len()), (0, 0, 1));
+    
+    // Tri-Fall (Parenthesized Fallback)
+    let missing: Option<i32> = None;
+    assert_eq!(tri!(missing => Some(value) <> (5) - 1), 4);
+    tri!(missing => Some[value] <> (5) - 1);
+    assert_eq!(value, 4);
+}
+
+#[test]
+fn tri_fall_trailing_comma() {
+    let one: Option<u8> = None;
+    let two = || Pair::Done;
+    let three = || Wide::Row(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+    
+    // Tri-Fall (One Value)
+    tri!(one => Some[a] <> 1);
+    tri!(one => Some[b] <> 2,);
+    assert_eq!((a, b), (1, 2));
+    assert_eq!(tri!(one => Some(value) <> 3,), 3);
+    
+    // Tri-Fall (Two Values)
+    tri!(two() => Pair::Both[low, high] <> 4, 5);
+    assert_eq!((low, high), (4, 5));
+    tri!(two() => Pair::Both[low, high] <> 6, 7,);
+    assert_eq!((low, high), (6, 7));
+    assert_eq!(tri!(two() => Pair::Both(low, high) <> 8, 9,), (8, 9));
+    
+    // Tri-Fall (Three Values)
+    tri!(three() => Wide::Flag[a, b, c, _] <> 1, false, 2);
+    assert_eq!((a, b, c), (1, false, 2));
+    tri!(three() => Wide::Flag[a, b, c, _] <> 3, true, 4,);
+    assert_eq!((a, b, c), (3, true, 4));
+    assert_eq!(tri!(three() => Wide::Flag(a, b, c, _) <> 5, true, 6,), (5, true, 6));
+    
+    // Tri-Fall (Guard, Tap, Retries)
+    tri!(one => Some[value] if value > 0 <> 10,);
+    assert_eq!(value, 10);
+    assert_eq!(tri!(two() => Pair::Both(low, high) if low < high <> 0, 1,), (0, 1));
+    tri!(one => Some[value] tap(|_| ()) <> 11,);
+    assert_eq!(value, 11);
+    assert_eq!(tri!(one => Some(value) %[2]<> 12,), 12);
+    
+    // Tri-Fall (Path, Rule)
+    let mut fell = 0;
+    tri!(one => None <> fell += 1,);
+    tri!(Some(two()) => Some(Pair::Both(..)) <> fell += 1,);
+    tri!(Some(two()) => Some(Pair::Both(..)) <> fell += 1);
+    tri!(Some(two()) => Some(Pair::Done) if fell > 9 <> fell += 1,);
+    assert_eq!(fell, 3);
+}
+
+#[test]
+fn tri_ref_field() {
+    let mut reader = Reader { buffer: Some(vec![3, 1, 2]) };
+    assert_eq!(reader.summary(), (3, 2, 6));
+    assert_eq!(reader.slice().len(), 3);
+    assert_eq!(reader.first(), 3);
+    
+    reader.push(4);
+    assert_eq!(reader.summary(), (3, 4, 10));
+    
+    reader.buffer = None;
+    reader.push(5);
+    assert_eq!(reader.summary(), (0, 0, 0));
+    assert_eq!(reader.slice(), &[0u8; 0]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn tri_fail_located() {
+    let parse = |port: Option<u16>| -> Result<u16, LocatedError> { tri!(port => Some[port] ->loc "missing port"); Ok(port) };
+    let location = format!("{}:{}", file!(), line!() - 1);
+    
+    assert_eq!(parse(Some(80)), Ok(80));
+    let error = parse(None).unwrap_err();
+    assert_eq!(error.location, location);
+    assert_eq!(error.message, "missing port");
+    assert_eq!(error.to_string(), format!("{location}: missing port"));
+    
+    let double = |port: Option<u16>| -> Result<u16, LocatedError> { Ok(tri!(port => Some(port) ->loc "no port") * 2) };
+    assert_eq!(double(Some(4)), Ok(8));
+    assert_eq!(double(None).unwrap_err().location, format!("{}:{}", file!(), line!() - 2));
+    
+    let check = |port: u16| -> Result<(), LocatedError> { tri!(port => [1..=1023] ->loc "unprivileged port"); Ok(()) };
+    assert!(check(22).is_ok());
+    assert_eq!(check(8080).unwrap_err().message, "unprivileged port");
+}
+
+#[test]
+fn tri_fall_tuple() {
+    let (a, b) = (3u8, 12u8);
+    
+    // Tri-Fall (Tuple)
+    assert_eq!(tri!((a, b) => [(1..=9, 1..=9)] <> 0, 0), (0, 0));
+    assert_eq!(tri!((a, a) => [(1..=9, 1..=9)] <> 0, 0,), (3, 3));
+    assert_eq!(tri!((a, b, a) => [(3, _, _) | (_, _, 0)] <> 1, 2, 3), (3, 12, 3));
+    assert_eq!(tri!((b, b, b) => [(3, _, _) | (_, _, 0)] <> 1, 2, 3), (1, 2, 3));
+    assert_eq!(tri!((Some(a), b) => [Some(1..), 10..] <> None, 0), (Some(3), 12));
+    
+    const ORIGIN: (u8, u8) = (0, 0);
+    assert_eq!(tri!((a, b) => ORIGIN <> 1, 1), (1, 1));
+    assert_eq!(tri!((0, 0) => ORIGIN <> 1, 1,), (0, 0));
+    
+    // Tri-Fall (Single)
+    let mut fell = false;
+    tri!((a, b) => [(1..=9, 1..=9)] <> fell = true);
+    assert!(fell);
+}
+
+#[test]
+#[cfg(feature = "traits")]
+fn tri_ext() {
+    let ports = ["80", "http", "443"];
+    
+    // Fall
+    let parsed: Vec<u16> = ports.iter().map(|port| port.parse().fall(0)).collect();
+    assert_eq!(parsed, [80, 0, 443]);
+    assert_eq!(None.fall(7), 7);
+    
+    // Fail
+    assert_eq!(Some(3).fail("missing"), Ok(3));
+    assert_eq!("x".parse::<u8>().fail("not a number"), Err("not a number"));
+    let total: Result<u16, &str> = ports.iter().map(|port| port.parse::<u16>().fail(*port)).sum();
+    assert_eq!(total, Err("http"));
+    
+    // Until
+    let mut queue = [None, None, Some(5)].into_iter();
+    assert_eq!(queue.next().unwrap().until(|| queue.next().unwrap()), 5);
+    
+    let mut attempts = 0;
+    let value = Err::<u8, _>(()).until(|| { attempts += 1; if attempts == 3 { Ok(attempts) } else { Err(()) } });
+    assert_eq!((value, attempts), (3, 3));
+    assert_eq!(Ok::<_, ()>(1).until(|| unreachable!()), 1);
+}
+
+#[test]
+fn tri_ignored_middle_field() {
+    let color = Color::Rgb(255, 128, 0);
+    
+    // Tri-Fall (Variant)
+    assert_eq!(tri!(color => Color::Rgb(r, _, b) <> (0, 0)), (255, 0));
+    assert_eq!(tri!(Color::Off => Color::Rgb(r, _, b) <> (1, 2)), (1, 2));
+    
+    // Tri-Fall
+    tri!(color => Color::Rgb[r, _, b] <> 0, 0);
+    assert_eq!((r, b), (255, 0));
+    
+    tri!(color => Color::Rgb[_, g, _] assert>);
+    assert_eq!(g, 128);
+}
+
+#[test]
+fn tri_all() {
+    assert_eq!(validate_form(Some("ada"), "36", 3), Ok(("ada", 36)));
+    assert_eq!(validate_form(Some("ada"), "old", 3), Err("validation failed"));
+    assert_eq!(validate_form(Some("ada"), "36", 0), Err("validation failed"));
+    assert_eq!(validate_form(None, "36", 3), Err("validation failed"));
+    
+    let mut checked = Vec::new();
+    let mut check = |field: &'static str, value: Option<u8>| { checked.push(field); value };
+    let result = (|| -> Result<(u8, u8), &str> {
+        tri_all!((check("a", Some(1)) => Some[a]), (check("b", None) => Some[b]), (check("c", Some(3)) => Some[_c]) -> "missing");
+        Ok((a, b))
+    })();
+    assert_eq!(result, Err("missing"));
+    assert_eq!(checked, ["a", "b"]);
+}
+
+#[test]
+fn tri_while_value() {
+    let mut passes = 0;
+    
+    // Tri-While (Variant)
+    let value = tri!(number_mangler(Some(value)) => Some(value = 0) >> passes += 1);
+    assert_eq!((value, passes), (20, 21));
+    
+    let mut total = 0;
+    let last = tri!(step(last) => Some(last = 0) >> total += last);
+    assert_eq!((last, total), (3, 6));
+    
+    let (low, high) = tri!(step_pair(low, high) => Pair::Both(low = 0, high = 9) >> ());
+    assert_eq!((low, high), (4, 5));
+    
+    let mut runs = 0;
+    let () = tri!(None::<()> => Some(_) >> runs += 1);
+    assert_eq!(runs, 1);
+}
+
+#[test]
+fn tri_weak_upgrade() {
+    let root = Rc::new(Node { name: "root", parent: Weak::new() });
+    let leaf = Node { name: "leaf", parent: Rc::downgrade(&root) };
+    
+    // Tri-Fall
+    tri!(leaf.parent.upgrade() => Some[parent] <> leaf_root());
+    assert!(Rc::ptr_eq(&parent, &root));
+    assert_eq!(parent_name(&leaf), "root");
+    assert_eq!(parent_name(&root), "none");
+    
+    drop(parent);
+    drop(root);
+    assert_eq!(parent_name(&leaf), "none");
+    assert_eq!(tri!(upgrade leaf.parent => Some(parent) <> leaf_root()).name, "orphan");
+}
+
+#[test]
+fn tri_map() {
+    // Tri-Map (Variant)
+    assert_eq!(tri!("21".parse::<u8>() => Ok(n) |> n * 2 <> 0), 42);
+    assert_eq!(tri!("x".parse::<u8>() => Ok(n) |> n * 2 <> 0), 0);
+    assert_eq!(tri!(Pair::Both(2, 5) => Pair::Both(low, high) |> high - low <> 0), 3);
+    assert_eq!(tri!(Some(4) => Some(n) |> [n; 2] <> [0, 0]), [4, 4]);
+    
+    // Tri-Map
+    tri!(Some("ada") => Some[name] |> name.len() <> 0);
+    assert_eq!(name, 3);
+    tri!(Pair::Both(1, 2) => Pair::Both[low, high] |> (high, low) <> 0, 0);
+    assert_eq!((low, high), (2, 1));
+    
+    assert_eq!(scaled_port("80"), Ok(8080));
+    assert_eq!(scaled_port("http"), Err("bad port"));
+    assert_eq!(doubled_port("21"), Ok(42));
+    assert_eq!(doubled_port("-"), Err("bad port"));
+    
+    // Tri-Map (Return)
+    let next = |text: &str| -> u8 { tri!(text.parse::<u8>() => Ok[n] |> n + 1 #> 0); n };
+    assert_eq!((next("6"), next("six")), (7, 0));
+}
+
+#[test]
+fn tri_strict_path() {
+    enum Signal {
+        Ready,
+    }
+    
+    enum Wrapper {
+        Only(#[allow(dead_code)] u8),
+    }
+    
+    // Tri-Strict
+    tri!(Signal::Ready => !Signal::Ready);
+    tri!(Wrapper::Only(3) => !Wrapper::Only);
+    
+    use Signal::*;
+    tri!(Ready => !Ready);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+fn tri_json() {
+    use serde_json::{json, Value};
+    
+    let read = |doc: &Value| -> Result<(String, u64, usize, bool), &'static str> {
+        tri!(doc => json::Object[fields] -> "expected object");
+        tri!(&fields["name"] => json::String[name] -> "expected name");
+        tri!(&fields["port"] => json::Number[port] -> "expected port");
+        tri!(&fields["tags"] => json::Array[tags] -> "expected tags");
+        tri!(&fields["secure"] => json::Bool[secure] -> "expected secure");
+        tri!(&fields["parent"] => json::Null -> "expected no parent");
+        
+        Ok((name.clone(), port.as_u64().unwrap_or(0), tags.len(), *secure))
+    };
+    
+    let doc = json!({ "name": "gate", "port": 443, "tags": ["a", "b"], "secure": true, "parent": null });
+    assert_eq!(read(&doc), Ok((String::from("gate"), 443, 2, true)));
+    
+    assert_eq!(read(&json!([1, 2])), Err("expected object"));
+    assert_eq!(read(&json!({ "name": 7 })), Err("expected name"));
+    assert_eq!(read(&json!({ "name": "gate", "port": 443, "tags": [], "secure": false, "parent": 1 })), Err("expected no parent"));
+    
+    tri!(json!(false) => json::Bool[flag] <> true);
+    assert!(!flag);
+    
+    tri!(json!("on") => json::String[mode] <> String::new());
+    assert_eq!(mode, "on");
+}
+
+#[test]
+fn tri_fall_block() {
+    let mut misses = Vec::new();
+    
+    tri!(None::<u8> => Some[port] <> { misses.push("caption"); 80 });
+    assert_eq!(port, 80);
+    
+    let port = tri!(None::<u16> => Some(port) <> { misses.push("variant"); 443 });
+    assert_eq!(port, 443);
+    
+    tri!(Some(5) => None <> { misses.push("path"); });
+    tri!(7 => [1..=5] <> { misses.push("rule"); });
+    tri!(9 => [1..=5] <> { misses.push("rule value"); 80 });
+    tri!(Some(2) => Some[scale] <> { misses.push("unreached"); 1 });
+    assert_eq!(scale, 2);
+    
+    assert_eq!(misses, ["caption", "variant", "path", "rule", "rule value"]);
+}
+
+#[test]
+fn tri_log_fall() {
+    let mut log = Vec::new();
+    
+    tri!(Some(8080) => Some[port] ?!> log.push("caption"), 80);
+    tri!(None::<u16> => Some[backup] ?!> log.push("caption"), 80);
+    assert_eq!((port, backup), (8080, 80));
+    
+    let (x, y) = tri!(Message::Quit => Message::Move { x, y, .. } ?!> log.push("struct"), 0, 0);
+    assert_eq!((x, y), (0, 0));
+    
+    let pair = tri!(Pair::Done => Pair::Both(low, high) ?!> log.push("variant"), (1, 2));
+    assert_eq!(pair, (1, 2));
+    
+    tri!(Some(3) => None ?!> log.push("path"), ());
+    tri!(4 => [1..=5] ?!> log.push("unreached"), ());
+    tri!(9 => [1..=5] ?!> log.push("rule"), ());
+    tri!(9 => [1..=5] ?!> log.push("rule value"), { 80 });
+    assert_eq!(tri!((9, 1) => [(1..=5, _)] ?!> log.push("tuple"), 5, 0), (5, 0));
+    
+    assert_eq!(log, ["caption", "struct", "variant", "path", "rule", "rule value", "tuple"]);
+}
+
+#[test]
+fn tri_cmp() {
+    use std::cmp::Ordering;
+    
+    let mut versions = [(1, 4, "b"), (0, 9, "z"), (1, 2, "a"), (1, 4, "a")];
+    versions.sort_by(|a, b| tri_cmp!(a.0, b.0; a.1, b.1; a.2, b.2));
+    assert_eq!(versions, [(0, 9, "z"), (1, 2, "a"), (1, 4, "a"), (1, 4, "b")]);
+    
+    versions.sort_by(|a, b| tri_cmp!(b.1, a.1; a.2, b.2));
+    assert_eq!(versions, [(0, 9, "z"), (1, 4, "a"), (1, 4, "b"), (1, 2, "a")]);
+    
+    let mut calls = 0;
+    let mut key = |value: u8| { calls += 1; value };
+    assert_eq!(tri_cmp!(1, 2; key(5), key(3)), Ordering::Less);
+    assert_eq!(tri_cmp!(2, 2; key(5), key(3)), Ordering::Greater);
+    assert_eq!(tri_cmp!(2, 2; 3, 3), Ordering::Equal);
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn tri_ref_mut_variant() {
+    let mut slot = Some(1);
+    let mut spare = 0;
+    
+    let count = tri!(slot => Some(ref mut count) <> &mut spare);
+    *count += 4;
+    assert_eq!(slot, Some(5));
+    
+    let count = tri!(&mut slot => Some(ref mut count) <> &mut spare);
+    *count *= 2;
+    assert_eq!(slot, Some(10));
+    assert_eq!(tri!(slot => Some(ref count: &u8) <> &0), &10);
+    
+    slot = None;
+    let count = tri!(slot => Some(ref mut count) <> &mut spare);
+    *count = 7;
+    assert_eq!((slot, spare), (None, 7));
+    
+    let slots = [Some(1), Some(2), None];
+    let mut index = 0;
+    let last = tri!(slots[index] => Some(ref last = &0) >> index += 1);
+    assert_eq!((*last, index), (2, 2));
+    
+    index = 0;
+    tri!(slots[index] => Some[ref last = &0] >> index += 1);
+    assert_eq!((*last, index), (2, 2));
+}
+
+#[test]
+fn tri_else() {
+    let check = |response: Result<u8, &'static str>| -> Result<u8, String> {
+        tri_else!(response => Err(e) -> format!("request failed: {e}"));
+        tri_else!(response => Ok[0] #> Ok(1));
+        Ok(response.unwrap() * 2)
+    };
+    
+    assert_eq!(check(Ok(4)), Ok(8));
+    assert_eq!(check(Ok(0)), Ok(1));
+    assert_eq!(check(Err("timeout")), Err(String::from("request failed: timeout")));
+    
+    let mut seen = Vec::new();
+    
+    for item in [Some(3), None, Some(12)] {
+        tri_else!(item => Some[count] <> seen.push(count));
+        tri_else!(item => None <> seen.push(0));
+        tri_else!(item => [Some(10..)] <> seen.push(100));
+    }
+    
+    assert_eq!(seen, [3, 0, 12, 100]);
+}
+
+#[test]
+fn tri_entry_variant() {
+    use std::collections::btree_map;
+    use std::collections::hash_map::{Entry, HashMap};
+    
+    let mut stock = HashMap::from([("bolt", 3)]);
+    
+    let mut restock = |item: &'static str, count: u32| -> Result<u32, &'static str> {
+        let mut entry = tri!(stock.entry(item) => Entry::Occupied(entry) -> "unknown item");
+        *entry.get_mut() += count;
+        Ok(*entry.get())
+    };
+    
+    assert_eq!(restock("bolt", 2), Ok(5));
+    assert_eq!(restock("nut", 2), Err("unknown item"));
+    
+    let mut add = |item: &'static str| -> Result<(), &'static str> {
+        tri!(stock.entry(item) => std::collections::hash_map::Entry::Vacant(slot) -> "already stocked").insert(0);
+        Ok(())
+    };
+    
+    assert_eq!(add("nut"), Ok(()));
+    assert_eq!(add("bolt"), Err("already stocked"));
+    assert_eq!(stock.get("nut"), Some(&0));
+    
+    let mut sizes = btree_map::BTreeMap::from([(1, "small")]);
+    
+    use btree_map::Entry::*;
+    let size = tri!(sizes.entry(1) => Occupied(entry) <> return).into_mut();
+    assert_eq!(*size, "small");
+    
+    tri!(sizes.entry(2) => Vacant[slot] #> ());
+    slot.insert("large");
+    assert_eq!(sizes.len(), 2);
+}
+
+#[test]
+fn tri_ok() {
+    assert_eq!(parse_port(Some("8080")), Ok(8080));
+    assert_eq!(parse_port(Some("http")), Err("not a port"));
+    assert_eq!(parse_port(None), Err("missing port"));
+    
+    assert_eq!(split_pair(Pair::Both(2, 3)), Ok((2, 3)));
+    assert_eq!(split_pair(Pair::Done), Err("done"));
+    
+    assert_eq!(even_code(4), Ok(4));
+    assert_eq!(even_code(5), Err("odd code"));
+}
+
+fn tri_fail_and_return(item: Option<bool>) -> Result<&'static str, &'static str> {
+    tri!(item => Some(a @ true) -> "Item was either None or False.");
+    tri!(item => Some[var_name] #> Err("Item was None."));
+    
+    if var_name { Ok("Item was True.") }
+    else { Err("Item was False.") }
+}
+
+fn print_person(name: &str, age: u8, height: (u8, u8)) {
+    println!("Name: {name}");
+    println!("Age: {age}");
+    println!("Height: {0}\'{1}\"", height.0, height.1);
+    println!();
+}
+
+fn number_mangler(item: Option<u8>) -> Option<u8> {
+    tri!(item => Some[var_name @ ..20] #> None);
+    Some(var_name + 1)
+}
+
+fn require_v4(addr: IpAddr) -> Result<Ipv4Addr, &'static str> {
+    tri!(addr => IpAddr::V4[v4] -> "need IPv4");
+    Ok(v4)
+}
+
+fn require_v6(addr: IpAddr) -> Result<Ipv6Addr, &'static str> {
+    let v6 = tri!(addr => std::net::IpAddr::V6(v6) -> "need IPv6");
+    Ok(v6)
+}
+
+fn step(value: u8) -> Option<u8> {
+    tri!(value => [..3] #> None);
+    Some(value + 1)
+}
+
+fn step_pair(low: u8, high: u8) -> Pair {
+    tri!(high - low => [3..] #> Pair::Done);
+    Pair::Both(low + 1, high - 1)
+}
+
+enum Pair {
+    Both(u8, u8),
+    Done,
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    tri!(future.as_mut().poll(&mut context) => Poll::Ready(output) %> ())
+}
+
+async fn cached_square(key: u8) -> u8 {
+    tri!(cache_get(key).await => Some[value] <> compute_square(key).await);
+    value
+}
+
+async fn cache_get(key: u8) -> Option<u8> {
+    tri!(key => [3] #> None);
+    Some(9)
+}
+
+async fn compute_square(key: u8) -> u8 {
+    key * key
+}
+
+struct Countdown {
+    remaining: u8,
+    output: Result<u8, &'static str>,
+}
+
+impl Future for Countdown {
+    type Output = Result<u8, &'static str>;
+    
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Self::Output> {
+        tri!(self.remaining => [0] #> { self.remaining -= 1; Poll::Pending });
+        Poll::Ready(self.output)
+    }
+}
+
+struct Doubled(Countdown);
+
+impl Future for Doubled {
+    type Output = Result<u8, &'static str>;
+    
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        tri!(Pin::new(&mut self.0).poll(cx) => Poll::Ready[output] ready>);
+        Poll::Ready(Ok(output? * 2))
+    }
+}
+
+fn split_chunk(packet: Packet<'_>) -> Result<(u8, &[u8]), &'static str> {
+    tri!(packet => Packet::Chunk[&[header, ref payload @ ..]] -> "empty chunk");
+    Ok((header, payload))
+}
+
+enum Packet<'a> {
+    Frame(u8, [u8; 4]),
+    Chunk(&'a [u8]),
+}
+
+fn read_line(line: Line) -> Result<Config, &'static str> {
+    let config = tri!(line => Line::Entry[host, port @ 1..] -> "bad line" as Config);
+    Ok(config)
+}
+
+#[derive(Debug)]
+enum Line {
+    Entry(&'static str, u16),
+    Comment,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Config {
+    host: &'static str,
+    port: u16,
+}
+
+#[derive(Clone, Copy)]
+enum Wide {
+    Row(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+    Flag(u8, bool, u8, bool),
+}
+
+fn drain(rx: &Receiver<u32>) -> Result<u32, &'static str> {
+    let mut total = 0;
+    
+    loop {
+        tri!(rx.try_recv() => Ok[number] %> [Err(TryRecvError::Empty)] -> "channel closed");
+        total += number;
+        
+        if total > 6 { return Ok(total) }
+    }
+}
+
+fn first_message(rx: &Receiver<u32>) -> Option<u32> {
+    let number = tri!(rx.try_recv() => Ok(number) %> [Err(TryRecvError::Empty)] #> None);
+    Some(number)
+}
+
+fn shout(name: Option<String>) -> Result<String, &'static str> {
+    tri!(name => Some[name] if is_lowercase(name.clone()) -> "not lowercase");
+    Ok(name.to_uppercase())
+}
+
+fn is_lowercase(text: String) -> bool {
+    text.chars().all(char::is_lowercase)
+}
+
+#[cfg(feature = "alloc")]
+fn collect_all(items: Vec<Option<u8>>) -> Result<Vec<u8>, &'static str> {
+    let values = tri!(items => each Some[value] -> "missing item");
+    Ok(values)
+}
+
+fn read_pointer(pointer: Option<NonNull<u8>>) -> Result<u8, &'static str> {
+    tri!(pointer => Some[deref_unsafe value] -> "null pointer");
+    Ok(*value)
+}
+
+fn sum_prefix(items: &[&str]) -> u32 {
+    let mut total = 0;
+    
+    'sum: {
+        for item in items {
+            // Tri-Fall (Break Block)
+            tri!(item.parse::<u32>() => Ok[number] <> break 'sum total);
+            total += number;
+        }
+        
+        total
+    }
+}
+
+fn first_filled(items: &[Option<u8>]) -> Option<usize> {
+    for (index, item) in items.iter().enumerate() {
+        tri!(item => none #> Some(index));
+    }
+    
+    None
+}
+
+#[allow(non_camel_case_types)]
+struct some(u8);
+
+fn scalar_fail(item: Option<u8>) -> Result<u8, ()> {
+    // Tri-Fail
+    tri!(item => Some[value] -> ());
+    let value: u8 = value;
+    
+    // Tri-Map-Err
+    tri!(Ok::<u8, ()>(value) => Ok[value] maperr> |_| ());
+    Ok(value)
+}
+
+#[cfg(feature = "std")]
+fn forward(tx: &mpsc::Sender<u32>, message: &str) -> Result<(), &'static str> {
+    // Tri-Emit
+    tri!(message.parse::<u32>().map(|number| number * 2) => Ok[doubled] emit> tx -> "process failed");
+    Ok(())
+}
+
+fn raw_digit(number: u8) -> Option<u8> {
+    // Tri-Return (Raw)
+    tri!(number => raw!(digit!()) #> None);
+    Some(number)
+}
+
+fn raw_pair(pair: Option<(u8, u8)>) -> Result<u8, &'static str> {
+    // Tri-Fail (Raw)
+    tri!(pair => raw!(both!(a, b)) -> "missing pair");
+    Ok(a + b)
+}
+
+#[derive(Debug, thiserror::Error)]
+enum LoadError {
+    #[error("could not read the count")]
+    Io(#[from] io::Error),
+    #[error("count isn't a number")]
+    Parse(#[from] ParseIntError),
+    #[error("count is empty")]
+    Empty,
+}
+
+impl PartialEq for LoadError {
+    fn eq(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+}
+
+fn load_count(read: io::Result<&str>) -> Result<u8, LoadError> {
+    // Tri-Convert (Propagate)
+    tri!(read => Ok[text] -?>);
+    tri!(text.parse::<u8>() => Ok[count] -?>);
+    
+    // Tri-Convert
+    tri!(count => [1..] -?> LoadError::Empty);
+    Ok(count)
+}
+
+fn load_setting(lines: &[&str], key: &str) -> Result<u8, LoadError> {
+    // Tri-Ok-Or
+    tri!(lines.iter().find(|line| line.starts_with(key)) => Some[line] ok_or> LoadError::Empty);
+    tri!(line.split_once('=') => Some[(_name, value)] ok_or> io::Error::from(io::ErrorKind::InvalidData));
+    Ok(value.trim().parse()?)
+}
+
+#[derive(Clone, Copy)]
+#[repr(i32)]
+enum Code {
+    Ok = 0,
+    Busy = 1,
+}
+
+const BUSY: i32 = Code::Busy as i32;
+
+fn check_code(code: Code) -> Result<(), &'static str> {
+    // Tri-Fail (Repr Path)
+    tri!(code => Code::Ok -> "ffi error");
+    Ok(())
+}
+
+fn check_raw(code: i32) -> Result<(), &'static str> {
+    // Tri-Fail (Raw Code)
+    if tri!(code => BUSY bool>) { return Err("busy") }
+    tri!(code => [0] -> "nonzero");
+    Ok(())
+}
+
+fn parse_all(lines: &[&str], errors: &mut Vec<ParseIntError>) -> u32 {
+    let mut total = 0;
+    
+    for line in lines {
+        tri!(line.parse::<u32>() => Ok[value] gather> errors);
+        total += value;
+    }
+    
+    total
+}
+
+#[cfg(feature = "std")]
+fn open_port(port: &str) -> Result<u16, ParseIntError> {
+    tri!(port.parse() => Ok[port] ->log);
+    Ok(port)
+}
+
+fn load_into(text: Option<&str>) -> Result<u8, LoadError> {
+    // Tri-Into
+    tri!(text => Some[text] ~> io::Error::other("missing"));
+    tri!(text.parse() => Ok[count: u8] ~> LoadError::Empty);
+    Ok(count)
+}
+
+enum Message {
+    Move { x: i32, y: i32, speed: u8 },
+    Quit,
+}
+
+enum State {
+    Loading,
+    Pending,
+    Ready(u8),
+    Failed,
+}
+
+struct Reader {
+    buffer: Option<Vec<u8>>,
+}
+
+impl Reader {
+    fn summary(&self) -> (u8, u8, u32) {
+        tri!(self.buffer => Some[ref data] <> &Vec::new());
+        
+        let first = data.first().copied().unwrap_or(0);
+        let last = data.last().copied().unwrap_or(0);
+        let total = data.iter().map(|&byte| byte as u32).sum();
+        (first, last, total)
+    }
+    
+    fn slice(&self) -> &[u8] {
+        tri!(self.buffer.as_deref() => Some[data] <> &[]);
+        data
+    }
+    
+    fn first(&self) -> u8 {
+        let data = tri!(self.buffer => Some(ref data) assert>);
+        data[0]
+    }
+    
+    fn push(&mut self, byte: u8) {
+        tri!(self.buffer => Some[ref mut data] <> return);
+        data.push(byte);
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Color {
+    Rgb(u8, u8, u8),
+    Off,
+}
+
+fn validate_form<'a>(name: Option<&'a str>, age: &str, level: u8) -> Result<(&'a str, u8), &'static str> {
+    tri_all!(
+        (name => Some[name]),
+        (age.parse::<u8>() => Ok[age]),
+        (level => [1..=5]),
+        -> "validation failed"
+    );
+    
+    Ok((name, age))
+}
+
+struct Node {
+    name: &'static str,
+    parent: Weak<Node>,
+}
+
+fn parent_name(node: &Node) -> &'static str {
+    // Tri-Fall (Upgrade)
+    tri!(upgrade node.parent => Some[parent] <> return "none");
+    parent.name
+}
+
+fn leaf_root() -> Rc<Node> {
+    Rc::new(Node { name: "orphan", parent: Weak::new() })
+}
+
+fn scaled_port(text: &str) -> Result<u16, &'static str> {
+    // Tri-Map (Fail)
+    tri!(text.parse::<u16>() => Ok[port] |> port * 100 + 80 -> "bad port");
+    Ok(port)
+}
+
+fn doubled_port(text: &str) -> Result<u16, &'static str> {
+    Ok(tri!(text.parse::<u16>() => Ok(port) |> port * 2 -> "bad port"))
+}
+
+fn parse_port(text: Option<&str>) -> Result<u16, &'static str> {
+    tri!(text => Some[text] -> "missing port");
+    tri!(text.parse() => Ok[port] =>ok "not a port")
+}
+
+fn split_pair(pair: Pair) -> Result<(u8, u8), &'static str> {
+    tri!(pair => Pair::Both(low, high) =>ok "done")
+}
+
+fn even_code(code: u8) -> Result<u8, &'static str> {
+    tri!(code % 2 => [0] =>ok "odd code").map(|_| code)
+}
+
+fn double_port(port: &str) -> Option<u16> {
+    let port = tri!(port.parse::<u16>() => Ok[port] opt>)?;
+    Some(port * 2)
+}
+
+#[cfg(feature = "std")]
+fn run_cli(args: &[&str]) -> ExitCode {
+    tri!(args.get(1) => Some[port] exit> 2);
+    tri!(port.parse::<u16>() => Ok[_] exit> ExitCode::FAILURE);
+    ExitCode::SUCCESS
+}
+
+fn find_item(items: &[Item], id: u8) -> Result<&Item, &'static str> {
+    tri!(items.iter().find(|item| item.id == id) => Some[found] -> "not found");
+    Ok(found)
+}
+
+struct Item {
+    id: u8,
+    weight: u8,
+}
+
+fn point_sum(point: Option<Point>) -> i32 {
+    tri!(point => [Some(Point(x, y))] <> return 0);
+    x + y
+}
+
+struct Point(i32, i32);
+
+const LIMIT: usize = 8;
+
+fn clamp_limit(index: usize) -> usize {
+    tri!(index => [0..LIMIT] #> 0);
+    index
+}
+
+fn clamp_index<const N: usize>(index: usize) -> usize {
+    // Rule (Const Range)
+    tri!(index => [0..N] #> 0);
+    index
+}
+
+fn checked_index<const N: usize>(index: usize) -> (Option<usize>, Result<usize, usize>) {
+    // Rule (Const Range Value)
+    let found: Option<usize> = tri!(index => [0..N] opt>);
+    let checked: Result<usize, usize> = tri!(index => [..N] =>ok index);
+    (found, checked)
+}
+
+fn bucket<const N: usize>(value: usize) -> (bool, bool, bool) {
+    let (mut low, mut below, mut high) = (true, true, true);
+    
+    // Rule (Const Range Inclusive, From)
+    tri!(value => [..=N] <> low = false);
+    tri!(value => [..N] <> below = false);
+    tri!(value => [N..] <> high = false);
+    (low, below, high)
+}
+
+const EMPTY: u8 = 0;
+
+struct Grid {
+    rows: Vec<Vec<u8>>,
+}
+
+impl Grid {
+    fn cell(&self, row: usize, column: usize) -> &u8 {
+        tri!(self.rows[row].get(column) => Some[cell] <> &EMPTY);
+        cell
+    }
+}
+
+fn read_config(port: &str) -> Result<u16, AppError> {
+    tri!(port.parse::<u16>() => Ok[port] maperr> |_| AppError::BadPort);
+    Ok(port)
+}
+
+fn read_pair(pair: Result<(u8, u8), ()>) -> Result<u8, AppError> {
+    let (a, b) = tri!(pair => Ok(pair) maperr> |()| AppError::Missing);
+    Ok(a + b)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum AppError {
+    BadPort,
+    Missing,
 }
 
 #[derive(Copy, Clone, Debug)]
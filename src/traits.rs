@@ -0,0 +1,52 @@
+//! Method forms of the common operators, for closures and iterator
+//! chains where a macro call is awkward. These only cover terms that
+//! bind nothing, and are enabled by the `traits` feature.
+
+/// A value that either hits with a `T` or misses, like a `Some` or `Ok`
+/// term in a tri expression.
+pub trait TriExt<T>: Sized {
+    /// Returns the matched value, or `None` on a miss.
+    fn tri(self) -> Option<T>;
+    
+    /// Returns the matched value or `default`, like `<>`.
+    fn fall(self, default: T) -> T {
+        match self.tri() {
+            Some(value) => value,
+            None => default,
+        }
+    }
+    
+    /// Returns the matched value as `Ok`, or `err` as `Err`, like `->`.
+    fn fail<E>(self, err: E) -> Result<T, E> {
+        match self.tri() {
+            Some(value) => Ok(value),
+            None => Err(err),
+        }
+    }
+    
+    /// Calls `step` for a new attempt until one matches, like `%>`.
+    /// Unlike the operator, the step gives back the next attempt, since
+    /// a method can't evaluate its receiver again.
+    fn until<F: FnMut() -> Self>(self, mut step: F) -> T {
+        let mut attempt = self;
+        
+        loop {
+            match attempt.tri() {
+                Some(value) => break value,
+                None => attempt = step(),
+            }
+        }
+    }
+}
+
+impl<T> TriExt<T> for Option<T> {
+    fn tri(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T, E> TriExt<T> for Result<T, E> {
+    fn tri(self) -> Option<T> {
+        self.ok()
+    }
+}
@@ -4,49 +4,115 @@
 /// Unlike the `?` operator, **tri!** allows you to easily specify
 /// what to do if unpacking fails.
 ///
-///     tri!(a => b $$ c);
+/// ```text
+/// tri!(a => b $$ c);
 ///
-///     a - Leading Expression
-///     b - Specified Term
-///     c - Trailing Expression
+/// a - Leading Expression
+/// b - Specified Term
+/// c - Trailing Expression
 ///
-///     $$ - Tri Operator
+/// $$ - Tri Operator
+/// ```
 ///
 /// ### Tri-Fail `->`
 ///
 /// Automatically returns the trailing expression in an error if
 /// the leading expression doesn't match the specified term.
 ///
-///     // Tri Expression
-///     tri!(item => Some(value) -> "Item was None!");
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) -> "Item was None!");
 ///
-///     // Expanded Form
-///     if let Some(value) = item { value }
-///     else { return Err("Item was None!"); }
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { return Err("Item was None!"); }
+/// ```
+///
+/// Words such as `box`, `loc` and `log` right after `->` pick one of
+/// the operators below. This is a breaking change for `-> log` with a
+/// local named `log`, which used to return `Err(log)`, so wrap the
+/// local in parentheses, as in `-> (log)`, to keep the plain Tri-Fail.
+///
+/// ### Tri-Fail `->box`
+///
+/// Boxes the trailing expression as a `dyn Error` before returning
+/// it in an error. Strings and error types can both be used, which
+/// suits functions returning `Result<T, Box<dyn Error>>`. This
+/// operator requires the `std` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) ->box "Item was None!");
+///
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { return Err(Box::<dyn Error + Send + Sync>::from("Item was None!")); }
+/// ```
 ///
 /// ### Tri-Fall `<>`
 ///
 /// Evaluates and uses the trailing expression as a fallback if
 /// the leading expression doesn't match the specified term.
 ///
-///     // Tri Expression
-///     tri!(item => Some(value) <> backup);
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) <> backup);
+///
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { backup }
+/// ```
+///
+/// If the trailing expression starts with `return`, `break`, or
+/// `continue`, variables bound by a rule stay in scope.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(point => [Point(x, y)] <> return);
+///
+/// // Expanded Form
+/// let Point(x, y) = point else { return };
+/// ```
+///
+/// A caption can fall back to a diverging expression too, such as
+/// breaking out of a fold with the total so far. Since `for` loops
+/// can't break with a value, break out of a labeled block instead.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item.parse() => Ok[number] <> break 'sum total);
+///
+/// // Expanded Form
+/// let number = if let Ok(number) = item.parse() { number }
+/// else { break 'sum total };
+/// ```
 ///
-///     // Expanded Form
-///     if let Some(value) = item { value }
-///     else { backup }
+/// The fallback can be a block with statements before its value. A
+/// caption or variant uses the value, while a path or rule without
+/// bindings only runs the block, so its value is discarded.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(port => Some[port] <> { warn("defaulting"); 80 });
+///
+/// // Expanded Form
+/// let port = if let Some(port) = port { port }
+/// else { warn("defaulting"); 80 };
+/// ```
 ///
 /// ### Tri-Return `#>`
 ///
 /// Similar to the `->` operator, but it doesn't wrap the return
 /// in an error.
 ///
-///     // Tri Expression
-///     tri!(item => Some(value) #> core::result::Result::Err(()));
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) #> core::result::Result::Err(()));
 ///
-///     // Expanded Form
-///     if let Some(value) = item { value }
-///     else { return core::result::Result::Err(()); }
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { return core::result::Result::Err(()); }
+/// ```
 ///
 /// ### Tri-Return `#> break`
 ///
@@ -55,82 +121,1605 @@
 /// can also be specified, and a trailing expression will be specified
 /// as well.
 ///
-///     // Tri Expression
-///     tri!(item => Some(value) #> break 'a true);
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) #> break 'a true);
+///
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { break 'a true; }
+/// ```
 ///
-///     // Expanded Form
-///     if let Some(value) = item { value }
-///     else { break 'a true; }
+/// A **continue** expression works the same way, skipping to the next
+/// pass of the enclosing loop, or of a labeled one.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some[value] #> continue 'outer);
+///
+/// // Expanded Form
+/// let Some(value) = item else { continue 'outer; };
+/// ```
 ///
 /// ### Tri-Until `%>`
 ///
-/// Performs the leading expression until its output matches the
-/// specified term.
+/// Performs the leading expression until its output matches the
+/// specified term.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) %> thing += 1);
+///
+/// // Expanded Form
+/// loop {
+///     if let Some(value) = item { break value; }
+///     thing += 1;
+/// }
+/// ```
+///
+/// Temporaries in the leading expression are dropped before the
+/// trailing expression runs, so a lock guard or `RefCell` borrow isn't
+/// held through it. Both sides can `.await` in an async function, and
+/// the future stays `Send` as long as the captured values are.
+///
+/// The loop evaluates to the captions of the matching pass. Variants
+/// give back their fields as a value, a tuple for several fields and
+/// `()` for none, while captions are bound in the enclosing scope.
+///
+/// ```text
+/// // Tri Expression
+/// let (id, body) = tri!(poll() => Ready(id, body) %> spin());
+/// tri!(poll() => Ready[id, body] %> spin());
+/// ```
+///
+/// The leading expression is evaluated again on every pass, so it
+/// can be used to fold over an iterator. The loop ends once the
+/// iterator is exhausted.
+///
+/// ```text
+/// // Sums every item of `iter` into `total`.
+/// tri!(iter.next().map(|x| total += x) => None %> ());
+/// ```
+///
+/// Fields can be narrowed with `@` patterns, which keeps the loop
+/// going until the value is also in range.
+///
+/// ```text
+/// // Polls until a reading between 1 and 100 comes in.
+/// tri!(sensor.read() => Some[level @ 1..=100] %> tick());
+/// ```
+///
+/// ### Tri-While `>>`
+///
+/// This operator acts like a `do-while` loop. The values are
+/// initialized with the given expressions, and the trailing
+/// expression is evaluated. The leading expression is then
+/// evaluated in a loop. For every time that the output matches
+/// the given variant, the trailing expression is evaluated with
+/// those values.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(do_stuff(number) => Some(value = 0) >> number += value);
+///
+/// // Expanded Form (Pseudo-Code)
+/// do(value = 0) { number += value; }
+/// while let Some(value) = do_stuff(number);
+/// ```
+///
+/// On the first pass the trailing expression sees the initial
+/// values. Each later pass sees the values from the latest match.
+/// Once the leading expression stops matching, the captions are
+/// bound to the values from the last pass. A variant gives those
+/// values back instead, so `let last = tri!(step(n) => Some(n = 0) >> ());`
+/// binds the final `n`.
+///
+/// ```text
+/// // Passes see `value` as 0, 1, 2, then 3.
+/// tri!(step(value) => Some[value = 0] >> seen.push(value));
+///
+/// // `step(3)` returned None, so `value` is left as 3.
+/// assert_eq!(value, 3);
+/// ```
+///
+/// ### Tri-Map-Err `maperr>`
+///
+/// Works like the `?` operator with a `map_err` applied first. The
+/// error payload is passed to the trailing expression, and the
+/// output is returned in an error. The term must be the `Ok` side
+/// of a result.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Ok[value] maperr> |e| AppError::Io(e));
+///
+/// // Expanded Form
+/// let value = match item {
+///     Ok(value) => value,
+///     Err(e) => return Err(AppError::Io(e)),
+/// };
+/// ```
+///
+/// ### Tri-Bool `bool>`
+///
+/// Checks the leading expression against the specified term
+/// without binding anything. It can be used as an `if` or `while`
+/// condition, and the leading expression is evaluated again for
+/// every check. To bind the captions on each pass of a loop, use
+/// `tri_loop!` instead.
+///
+/// ```text
+/// // Tri Expression
+/// if tri!(item => Some[_] bool>) { ... }
+///
+/// // Expanded Form
+/// if matches!(item, Some(_)) { ... }
+/// ```
+///
+/// ### Tri-Exit `exit>`
+///
+/// Returns an `ExitCode` from the enclosing function, usually
+/// `main`. The trailing expression can be an `ExitCode` or a `u8`.
+/// This operator requires the `std` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Ok[value] exit> 2);
+///
+/// // Expanded Form
+/// let Ok(value) = item else {
+///     return ExitCode::from(2);
+/// };
+/// ```
+///
+/// ### Tri-Flag `flag>`
+///
+/// Sets the trailing place expression to `true` and continues to
+/// the next iteration of the enclosing loop.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Ok[value] flag> had_error);
+///
+/// // Expanded Form
+/// let Ok(value) = item else {
+///     had_error = true;
+///     continue;
+/// };
+/// ```
+///
+/// ### Tri-Option `opt>`
+///
+/// Turns the match into an option. Captions and variant fields are
+/// returned in `Some` when the term matches. Paths and rules return
+/// the leading expression itself.
+///
+/// ```text
+/// // Tri Expression
+/// let value = tri!(item => Ok[value] opt>);
+///
+/// // Expanded Form
+/// let value = if let Ok(value) = item { Some(value) }
+/// else { None };
+/// ```
+///
+/// ### Tri-Ready `ready>`
+///
+/// Returns `Poll::Pending` from the enclosing function. This is
+/// meant for `Future` implementations that poll an inner future.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(inner.poll(cx) => Poll::Ready[result] ready>);
+///
+/// // Expanded Form
+/// let Poll::Ready(result) = inner.poll(cx) else {
+///     return Poll::Pending;
+/// };
+/// ```
+///
+/// ### Struct Captions `as`
+///
+/// Ending a caption expression with `as` and a struct name builds
+/// the struct from the captions instead of binding them. Each
+/// caption must share its name with a field of the struct. This
+/// works with the `->`, `<>`, `#>`, and `%>` operators, and with
+/// `opt>` to give back `Some` of the struct or `None`.
+///
+/// ```text
+/// // Tri Expression
+/// let config = tri!(line => Entry[host, port] -> "bad line" as Config);
+///
+/// // Expanded Form
+/// let config = if let Entry(host, port) = line { Config { host, port } }
+/// else { return Err("bad line"); };
+///
+/// // Tri Expression
+/// let config = tri!(section => Server[host, port] opt> as Config);
+///
+/// // Expanded Form
+/// let config = if let Server(host, port) = section { Some(Config { host, port }) }
+/// else { None };
+/// ```
+///
+/// ### Tri-Until Retry `%> [..] ->`
+///
+/// Keeps polling the leading expression while it matches one of the
+/// bracketed retry patterns, and returns early on anything else. This
+/// fits results with more than one error kind, like `try_recv`, where
+/// `Empty` should be retried and `Disconnected` should not. `#>` can
+/// be used in place of `->` to return the expression as is.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(rx.try_recv() => Ok[msg] %> [Err(TryRecvError::Empty)] -> "channel closed");
+///
+/// // Expanded Form
+/// let msg = loop {
+///     match rx.try_recv() {
+///         Ok(msg) => break msg,
+///         Err(TryRecvError::Empty) => core::hint::spin_loop(),
+///         _ => return Err("channel closed"),
+///     }
+/// };
+/// ```
+///
+/// ### Field Fallbacks `(a <> 0)`
+///
+/// A variant pattern without an operator can give each field its own
+/// fallback. Every field is checked on its own, so a field whose
+/// pattern fails falls back without affecting the other fields. If
+/// the variant itself doesn't match, every field falls back.
+///
+/// ```text
+/// // Tri Expression
+/// let (a, b) = tri!(pair => Both(a @ 1..=9 <> 0, b @ 1..=9 <> 1));
+///
+/// // Expanded Form
+/// let (a, b) = match pair {
+///     Both(a, b) => (
+///         if let a @ 1..=9 = a { a } else { 0 },
+///         if let b @ 1..=9 = b { b } else { 1 },
+///     ),
+///     _ => (0, 1),
+/// };
+/// ```
+///
+/// ### Tri-Assert `assert>`
+///
+/// Panics with the rejected value if the pattern doesn't match, much
+/// like `assert_eq!` but with the full power of patterns. The value
+/// must implement `Debug`. Captions are still bound on success.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(score => [0..=100] assert>);
+///
+/// // Expanded Form
+/// let __tri_val = score;
+/// let 0..=100 = __tri_val else {
+///     panic!("assertion `score matches 0..=100` failed\n  value: {:?}", __tri_val)
+/// };
+/// ```
+///
+/// ### Guards `if`
+///
+/// Captions and variants can be followed by an `if` guard, which works
+/// like a guard on a match arm. These terms are lowered to a `match`,
+/// so a failed guard picks the fallback the same way a failed pattern
+/// does. Guards work with the `->`, `<>`, and `#>` operators. A
+/// diverging fallback like `<> continue` skips items that fail either
+/// the pattern or the guard.
+///
+/// Bindings are moved into the success branch, but the guard only sees
+/// them by shared reference. A guard can't consume a binding, so call
+/// by-value functions on a copy or a clone instead.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(entry => Member[name, age] if age >= 18 <> (String::new(), 0));
+///
+/// // Expanded Form
+/// let (name, age) = match entry {
+///     Member(name, age) if age >= 18 => (name, age),
+///     _ => (String::new(), 0),
+/// };
+/// ```
+///
+/// Rules take guards as well. Since their bindings stay in scope, a
+/// guarded rule with a diverging fallback checks the pattern first and
+/// then the guard, taking the same fallback when either one fails.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(pair => [index, Some(weight)] if weight > 2 <> continue);
+///
+/// // Expanded Form
+/// let (index, Some(weight)) = pair else { continue };
+/// if !(weight > 2) { continue }
+/// ```
+///
+/// ### Tri-Yield `yield>`
+///
+/// Yields a value from the surrounding coroutine each time the pattern
+/// doesn't match, then tries the leading expression again once the
+/// coroutine is resumed. This requires the `nightly` feature, and the
+/// calling crate must enable `#![feature(coroutines)]`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(state.take() => Some[job] yield> Event::Stalled);
+///
+/// // Expanded Form
+/// let job = loop {
+///     if let Some(job) = state.take() { break job }
+///     else { yield Event::Stalled; }
+/// };
+/// ```
+///
+/// ### Tri-Each `each`
+///
+/// Placing `each` before a term matches it against every item of the
+/// leading expression, collecting the captions of each item into a
+/// `Vec`. The operator is applied to every item on its own, so `->`
+/// returns the error at the first item that doesn't match. This
+/// requires the `alloc` feature.
+///
+/// ```text
+/// // Tri Expression
+/// let values = tri!(items => each Some[v] -> "missing item");
+///
+/// // Expanded Form
+/// let mut values = Vec::new();
+/// for item in items {
+///     values.push(if let Some(v) = item { v } else { return Err("missing item") });
+/// }
+/// ```
+///
+/// ### Unsafe Dereferencing `deref_unsafe`
+///
+/// Prefixing a caption with `deref_unsafe` turns a bound `NonNull<T>`
+/// into a `&T` inside an `unsafe` block that the macro generates. By
+/// writing it, the caller promises that the pointer is valid, aligned,
+/// and not mutated for as long as the reference is used. It only works
+/// with operators that bind statements, such as `->`, `<>`, and `#>`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(maybe_ptr => Some[deref_unsafe value] -> "null pointer");
+///
+/// // Expanded Form
+/// let Some(value) = maybe_ptr else { return Err("null pointer") };
+/// let value = unsafe { NonNull::as_ref(&value) };
+/// ```
+///
+/// ### Tapping `tap(..)`
+///
+/// A `tap` before the `->` or `<>` operator runs a closure with a
+/// reference to the captions, much like `Iterator::inspect`. The
+/// closure only runs when the term matches, so fallbacks aren't
+/// tapped.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(x => Some[v] tap(|v| record(*v)) <> 0);
+///
+/// // Expanded Form
+/// let v = if let Some(v) = x { (|v| record(*v))(&v); v } else { 0 };
+/// ```
+///
+/// ### Newtype Wrappers
+///
+/// Single field wrappers like `Reverse`, `Wrapping`, and `Saturating`
+/// are matched like any other tuple struct. They need to be imported
+/// or written with their full path, such as `std::cmp::Reverse[v]`.
+/// Since their patterns can't fail, a caption with `<>` never falls
+/// back and doesn't warn about the irrefutable pattern.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(Wrapping(u8::MAX) + Wrapping(2) => Wrapping[wrapped] <> 0);
+///
+/// // Expanded Form
+/// let Wrapping(wrapped) = Wrapping(u8::MAX) + Wrapping(2);
+/// ```
+///
+/// ### Option Shorthand `some` `none`
+///
+/// `some v` is shorthand for `Some[v]`, and `none` is shorthand for
+/// `None`. A type named `some` can still be matched with brackets or
+/// parentheses, and paths like `none::Thing` aren't affected.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => some value <> 0);
+/// tri!(item => none #> return);
+///
+/// // Expanded Form
+/// let value = if let Some(value) = item { value } else { 0 };
+/// let None = item else { return };
+/// ```
+///
+/// ### Tri-Emit `emit>`
+///
+/// Sends the captions through a channel when the term matches, and
+/// returns the trailing expression as an error when it doesn't. The
+/// error is also returned if the receiver has hung up. This requires
+/// the `std` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(process(msg) => Ok[out] emit> tx -> "process failed");
+///
+/// // Expanded Form
+/// if let Ok(out) = process(msg) {
+///     if tx.send(out).is_err() { return Err("process failed") }
+/// } else { return Err("process failed") }
+/// ```
+///
+/// ### Raw Patterns `raw!`
+///
+/// `raw!` passes its contents through as a single rule, skipping the
+/// caption and path parsing. This lets a term be any pattern, including
+/// one produced by a pattern macro.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(pair => raw!(both!(a, b)) -> "missing");
+///
+/// // Expanded Form
+/// let both!(a, b) = pair else { return Err("missing") };
+/// ```
+///
+/// ### Tri-Convert `-?>`
+///
+/// Like the `->` operator, but the error is converted with `From` the
+/// same way `?` would convert it. Without a trailing expression, the
+/// original `Err` is converted and returned, which works well with
+/// errors that derive `From` impls, such as `#[from]` in `thiserror`.
+/// With a trailing expression it also stands in for `.ok_or(..)?` when
+/// an `Option` is matched in a function returning `Result`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(read_file() => Ok[text] -?>);
+///
+/// // Expanded Form
+/// let text = match read_file() {
+///     Ok(text) => text,
+///     Err(error) => return Err(From::from(error)),
+/// };
+/// ```
+///
+/// ### Tri-Into `~>`
+///
+/// Like the `->` operator, but the trailing expression is converted
+/// with `Into` into the error type of the function, the same way `?`
+/// converts errors.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(value) ~> "Item was None!");
+///
+/// // Expanded Form
+/// if let Some(value) = item { value }
+/// else { return Err(Into::into("Item was None!")); }
+/// ```
+///
+/// ### Tracked Tri-Until `@ (count, last)`
+///
+/// Adding `@ (count, last)` between a caption and the `%>` operator
+/// binds two more values once the loop ends. `count` is the number of
+/// times the leading expression was evaluated, including the match,
+/// and `last` is an `Option` holding the last rejected value.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(poll() => Some[v] @ (count, last) %> tick());
+///
+/// // Expanded Form
+/// let (v, count, last) = {
+///     let mut count = 0;
+///     let mut last = None;
+///     loop {
+///         count += 1;
+///         match poll() {
+///             Some(v) => break (v, count, last),
+///             rejected => { last = Some(rejected); tick(); }
+///         }
+///     }
+/// };
+/// ```
+///
+/// ### Field Guards `a if ..`
+///
+/// A guard can also be written right after a field, where it can use
+/// any field of the term. Field guards are joined with `&&` and checked
+/// like a guard written after the term.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(range => Span(start, end if end > start) <> (0, 0));
+///
+/// // Expanded Form
+/// match range {
+///     Span(start, end) if end > start => (start, end),
+///     _ => (0, 0),
+/// }
+/// ```
+///
+/// ### Jittered Tri-Until `%>jitter(..)`
+///
+/// Retries the leading expression with an exponential backoff. After
+/// every rejected attempt it sleeps for `base` milliseconds, multiplied
+/// by `factor` for each earlier attempt and by a random jitter from the
+/// given RNG. A closure taking the `Duration` can be passed last to
+/// replace `std::thread::sleep`. This requires the `rand` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(connect() => Ok[conn] %>jitter(&mut rng, 50, 2));
+///
+/// // Expanded Form
+/// let mut attempt = 0;
+/// let conn = loop {
+///     if let Ok(conn) = connect() { break conn }
+///     std::thread::sleep(jitter::delay(&mut rng, 50, 2, attempt));
+///     attempt += 1;
+/// };
+/// ```
+///
+/// ### FFI Return Codes
+///
+/// Fieldless `#[repr(i32)]` or `#[repr(C)]` enums are matched like any
+/// other path, including paths with more than one segment. A raw code
+/// can be matched with a rule, but a cast like `Code::Ok as i32` isn't
+/// a pattern, so name the discriminant with a `const` to match it.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(code => Code::Ok -> "ffi error");
+/// tri!(raw_code => [0] -> "nonzero");
+///
+/// // Expanded Form
+/// let Code::Ok = code else { return Err("ffi error") };
+/// let 0 = raw_code else { return Err("nonzero") };
+/// ```
+///
+/// ### Tri-Gather `gather>`
+///
+/// Pushes the payload of an `Err` into the trailing collection and
+/// continues to the next iteration of the enclosing loop. This keeps
+/// a batch going while gathering every error instead of stopping at
+/// the first one.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(line.parse() => Ok[value] gather> errors);
+///
+/// // Expanded Form
+/// let result = line.parse();
+/// let Ok(value) = result else {
+///     if let Err(error) = result { errors.push(error) }
+///     continue
+/// };
+/// ```
+///
+/// ### Typed Rules `[n: Type]`
+///
+/// A rule made of a single binding can be given a type, the same as
+/// a `let` statement. This settles integer and `parse` inference in
+/// generic code. A bare binding always matches, so only `<>` can
+/// follow it, and the fallback is type checked but never evaluated.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(offset + 2 => [mut n: i64] <> 0);
+///
+/// // Expanded Form
+/// let mut n: i64 = offset + 2;
+/// let _ = || -> i64 { 0 };
+/// ```
+///
+/// ### Tri-While Count `>>count`
+///
+/// Loops while the leading expression matches the specified term, and
+/// binds the number of matches to the trailing identifier afterwards.
+/// An expression after a comma is evaluated with the captions on each
+/// match. Unlike `>>`, the captions don't need initial values, since
+/// they only live inside the loop.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(items.next() => Some[item] >>count processed, total += item);
+///
+/// // Expanded Form
+/// let mut processed: usize = 0;
+/// while let Some(item) = items.next() {
+///     processed += 1;
+///     total += item;
+/// }
+/// ```
+///
+/// ### Tri-Fail `->log`
+///
+/// Logs an `Err` and returns it unchanged, like `?` without the
+/// conversion. With the `log` feature the error goes through
+/// `log::error!`, and otherwise it is printed with `eprintln!`. The
+/// term has to be a `Result`, and an `Ok` it doesn't match panics,
+/// since there's no error to return.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(connect() => Ok[conn] ->log);
+///
+/// // Expanded Form
+/// let conn = match connect() {
+///     Ok(conn) => conn,
+///     Err(e) => { log::error!("connect() failed: {:?}", e); return Err(e) }
+/// };
+/// ```
+///
+/// ### Tri-Entry `map, key =>`
+///
+/// Looks up a key in a map, inserting the fallback when the key is
+/// missing, and binds a mutable reference to the stored value. Any
+/// collection implementing `OrInsert` can be used, which includes
+/// `BTreeMap` with the `alloc` feature and `HashMap` with `std`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(cache, id => user <> load_user(id));
+///
+/// // Expanded Form
+/// let user = cache.entry(id).or_insert_with(|| load_user(id));
+/// ```
+///
+/// ### Nested Captions `Some(Some[v])`
+///
+/// A field can itself be a caption of plain identifiers, which binds
+/// them through both layers. Nested options can also be flattened
+/// with `flatten` before the term.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(nested => Some(Some[value]) <> 0);
+/// tri!(nested => flatten Some[value] <> 0);
+///
+/// // Expanded Form
+/// if let Some(Some(value)) = nested { value } else { 0 }
+/// let value = if let Some(value) = nested.flatten() { value } else { 0 };
+/// ```
+///
+/// ### Tuple Captions `Some[(a, b)]`
+///
+/// A caption of parenthesized identifiers binds the fields of a tuple
+/// held in one field, and counts as a single capture, so its fallback
+/// is one tuple. This differs from `Pair::Both(a, b)`, which binds two
+/// separate fields of the variant itself.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(pair => Some[(a, b)] <> (0, 0));
+///
+/// // Expanded Form
+/// let (a, b) = if let Some((a, b)) = pair { (a, b) } else { (0, 0) };
+/// ```
+///
+/// ### Tri-Fall `<else e>`
+///
+/// Binds the payload of an `Err` to the given name and evaluates
+/// the trailing expression with it as the fallback, the same as
+/// `Result::unwrap_or_else`. Since there's no other fallback, the
+/// term has to cover every `Ok` value.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Ok(value) <else e> recover(e));
+///
+/// // Expanded Form
+/// match item { Ok(value) => value, Err(e) => recover(e) }
+/// ```
+///
+/// ### Typed Captions `[n: Type]`
+///
+/// A caption can be given a type, which is checked against the bound
+/// value after the term. This drives inference the same way a typed
+/// `let` would, so `parse` picks up its target from the caption. The
+/// check is a statement, so it only works with statement operators.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(text.parse() => Ok[count: u32] -> "not a number");
+///
+/// // Expanded Form
+/// let Ok(count) = text.parse() else { return Err("not a number") };
+/// let _: u32 = count;
+/// ```
+///
+/// ### Tri-Status `status>`
+///
+/// Returns a gRPC `Status` built from the code and message in the
+/// trailing tuple, which suits `tonic` service handlers. This requires
+/// the `tonic` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(request.name => Some[name] status> (Code::InvalidArgument, "name required"));
+///
+/// // Expanded Form
+/// let Some(name) = request.name else {
+///     return Err(Status::new(Code::InvalidArgument, "name required"))
+/// };
+/// ```
+///
+/// ### Tri-Http `http>`
+///
+/// Returns an `http::Response` with the status and body in the
+/// trailing tuple, as the error of a handler returning `Result`. The
+/// body is converted with `Into`. This requires the `http` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(parse_body() => Ok[body] http> (StatusCode::BAD_REQUEST, "invalid body"));
+///
+/// // Expanded Form
+/// let Ok(body) = parse_body() else {
+///     let mut response = Response::new(Into::into("invalid body"));
+///     *response.status_mut() = StatusCode::BAD_REQUEST;
+///     return Err(response)
+/// };
+/// ```
+///
+/// ### Loop Labels `'label:`
+///
+/// Tri-Until and Tri-While can be given a label before the leading
+/// expression. The label is put on the generated loop, so a nested
+/// **tri!** in the trailing expression can break out of it. Loops
+/// over captions and variants give back a value, so breaking out of
+/// them needs a value for the captions as well.
+///
+/// ```text
+/// // Tri Expression
+/// tri!('poll: read() => None %> tri!(budget => [1..] #> break 'poll));
+///
+/// // Expanded Form
+/// 'poll: loop {
+///     if let None = read() { break }
+///     else { let 1.. = budget else { break 'poll }; }
+/// }
+/// ```
+///
+/// ### Tri-Future `future>`
+///
+/// Gives back an immediately ready future holding `Ok` with the
+/// captions, or `Err` with the trailing expression. This fits into
+/// future combinator chains, and requires the `futures` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(validate(request) => Ok[body] future> bad_request());
+///
+/// // Expanded Form
+/// if let Ok(body) = validate(request) { future::ready(Ok(body)) }
+/// else { future::ready(Err(bad_request())) }
+/// ```
+///
+/// ### Capped Tri-Until `%[n]>`
+///
+/// Gives up on the Tri-Until after `n` retries, then either returns
+/// the error after `->` or falls back to the values after `<>`, as
+/// Tri-Fall does. Paths and rules bind nothing, so their fallback is
+/// only evaluated. A cap of `0` checks the leading expression once
+/// and never retries.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(read() => Ok[value] %[16]> retry(), -> "gave up");
+/// tri!(read() => Ok[value] %[16]> retry(), <> 0);
+///
+/// // Expanded Form
+/// let mut tries: usize = 0;
+/// let found = loop {
+///     if let Ok(value) = read() { break Some(value) }
+///     else if tries >= 16 { break None }
+///     else { retry(); tries += 1; }
+/// };
+/// let Some(value) = found else { return Err("gave up") };
+/// let value = match found { Some(value) => value, None => 0 };
+/// ```
+///
+/// ### Tri-Panic `!>`
+///
+/// Panics with the trailing message when the term doesn't match, for
+/// functions that can't return early. The message takes the same
+/// arguments as `panic!`, and leaving it out calls `unreachable!`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(table.get(key) => Some[value] !> "key {key} must exist");
+///
+/// // Expanded Form
+/// let Some(value) = table.get(key) else { panic!("key {key} must exist") };
+/// ```
+///
+/// ### Binding Modes
+///
+/// Every term is lowered to a plain `let`, `if let`, or `match`, so
+/// default binding modes apply as usual. Matching through a `&` or
+/// `&mut` binds captions by reference without writing `ref`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(&name => Some[user] <> &guest);
+///
+/// // Expanded Form
+/// let user: &String = if let Some(user) = &name { user } else { &guest };
+/// ```
+///
+/// The leading expression is matched in place rather than moved into a
+/// temporary, so a `ref` caption borrows straight from a field and can
+/// be used for as long as the field is borrowed.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(self.buffer => Some[ref data] <> &Vec::new());
+///
+/// // Expanded Form
+/// let data = if let Some(ref data) = self.buffer { data } else { &Vec::new() };
+/// ```
+///
+/// Variants take `ref` and `ref mut` the same way. A `ref mut` binding
+/// can be written through after the term, but it can't carry a
+/// Tri-While value, since the last borrow is still held when the place
+/// is checked again.
+///
+/// ```text
+/// // Tri Expression
+/// *tri!(slot => Some(ref mut count) <> &mut spare) += 1;
+///
+/// // Expanded Form
+/// *(if let Some(ref mut count) = slot { count } else { &mut spare }) += 1;
+/// ```
+///
+/// ### Tri-Fail `->span(..)`
+///
+/// Records a `tracing` event at the error level and returns the message
+/// as the error, converted with `Into`. Structured fields can follow the
+/// message, written the same way as for `tracing::error!`. This requires
+/// the `tracing` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(form.get("name") => Some[name] ->span("missing field", field = "name"));
+///
+/// // Expanded Form
+/// let Some(name) = form.get("name") else {
+///     tracing::error!(field = "name", "missing field");
+///     return Err(Into::into("missing field"))
+/// };
+/// ```
+///
+/// ### Tri-Default `?:`
+///
+/// Falls back to `Default::default()`, a shorthand for the common
+/// `<> Default::default()`. The captured type must implement `Default`,
+/// and several captions fall back to a tuple of defaults.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(map.get(key) => Some[value] ?:);
+///
+/// // Expanded Form
+/// let value = if let Some(value) = map.get(key) { value }
+/// else { Default::default() };
+/// ```
+///
+/// ### Environment Variables `env`
+///
+/// Starting with `env` and a string literal reads that environment
+/// variable with `std::env::var`, giving a `Result<String, VarError>`
+/// to match against. This requires the `std` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(env "PORT" => Ok[port] <> "8080".into());
+///
+/// // Expanded Form
+/// let port = if let Ok(port) = std::env::var("PORT") { port }
+/// else { "8080".into() };
+/// ```
+///
+/// ### Tri-Cache `cache>`
+///
+/// Stores a clone of the captions in the trailing place on success, or
+/// falls back to a clone of what the place held before. The captured
+/// type must implement `Clone`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(recompute() => Ok[value] cache> self.cached);
+///
+/// // Expanded Form
+/// let value = if let Ok(value) = recompute() { self.cached = value.clone(); value }
+/// else { self.cached.clone() };
+/// ```
+///
+/// ### Struct Variants `{ a, b }`
+///
+/// Struct-like variants are matched by field name, and evaluate to the
+/// bound fields like a tuple variant does. A field can be renamed with
+/// `a: name`, checked against a pattern with `a: 0..=9`, or left out
+/// with a trailing `..`. Only plain and renamed fields are given back.
+///
+/// ```text
+/// // Tri Expression
+/// let (x, y) = tri!(msg => Message::Move { x, y: top, .. } <> (0, 0));
+///
+/// // Expanded Form
+/// let (x, y) = if let Message::Move { x, y: top, .. } = msg { (x, top) }
+/// else { (0, 0) };
+/// ```
+///
+/// ### Transition Tables `[(a, b) | ..]`
+///
+/// A rule can be an or-pattern over tuples, which checks a value
+/// against a table of allowed pairs. Unit variants in the table bind
+/// nothing, so the rule only validates.
+///
+/// ```text
+/// // Tri Expression
+/// tri!((from, to) => [(Open, Closed) | (Closed, Open)] -> "invalid transition");
+///
+/// // Expanded Form
+/// let (Open, Closed) | (Closed, Open) = (from, to)
+/// else { return Err("invalid transition") };
+/// ```
+///
+/// ### Or-Patterns `A | B`
+///
+/// A rule can hold an or-pattern, matching any of its alternatives, and
+/// commas still split a rule into a tuple. A lone rule is matched
+/// against the value as is, never as a one-element tuple. Plain paths
+/// can be joined by `|` without brackets, which is passed on as a
+/// single rule.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(poll() => State::Ready | State::Closed %> spin());
+/// tri!(pair => [Some(1 | 2), Ok(_) | Err(0)] -> "unexpected");
+///
+/// // Expanded Form
+/// loop { let State::Ready | State::Closed = poll() else { spin(); continue }; break }
+/// let (Some(1 | 2), Ok(_) | Err(0)) = pair else { return Err("unexpected") };
+/// ```
+///
+/// ### Const Bounds `[0..N]`
+///
+/// Const generic parameters can't be used in patterns, so a lone range
+/// rule with an identifier for a bound is checked with `contains`
+/// instead. This covers `..`, `..=` and open ranges, and the bound can
+/// be a const generic, a const item or a local. Operators that give
+/// the value back, such as `opt>` and `=>ok`, still give the matched
+/// value rather than the result of the check.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(index => [0..N] #> 0);
+/// tri!(index => [0..N] opt>);
+///
+/// // Expanded Form
+/// let true = (0..N).contains(&index) else { return 0 };
+/// match index { value if (0..N).contains(&value) => Some(value), _ => None }
+/// ```
+///
+/// ### Try Blocks
+///
+/// The leading expression can be a `try` block on nightly, which lets
+/// `?` short-circuit into a fallback instead of the enclosing function.
+/// The calling crate must enable `#![feature(try_blocks)]`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(try { a.parse::<u8>()? + b.parse::<u8>()? } => Ok(total) <> 0);
+///
+/// // Expanded Form
+/// let total = if let Ok(total) = try { a.parse::<u8>()? + b.parse::<u8>()? } { total }
+/// else { 0 };
+/// ```
+///
+/// ### Tri-Fall Retries `%[n]<>`
+///
+/// Retries the leading expression up to `n` times, then falls back to
+/// the trailing expression once every try has missed. The count is
+/// the same as the cap of the Capped Tri-Until, so a count of `0`
+/// checks the leading expression once and never retries.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(attempt() => Ok[value] %[3]<> default_value);
+///
+/// // Expanded Form
+/// let mut tries: usize = 0;
+/// let value = loop {
+///     if let Ok(value) = attempt() { break value }
+///     if tries >= 3 { break default_value }
+///     tries += 1;
+/// };
+/// ```
+///
+/// ### Tri-Fail `->loc`
+///
+/// Returns a `LocatedError` holding the message and the `file:line` of
+/// the `tri!` call, converted with `Into`. This gives cheap provenance
+/// for errors without a backtrace, and requires the `alloc` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(config.get("port") => Some[port] ->loc "missing port");
+///
+/// // Expanded Form
+/// let Some(port) = config.get("port") else {
+///     return Err(Into::into(LocatedError::new(concat!(file!(), ":", line!()), "missing port")))
+/// };
+/// ```
+///
+/// ### Tuple Fallbacks
+///
+/// Paths and rules fall back with a statement when given one trailing
+/// expression. Given several, the term evaluates to the matched value
+/// or to a tuple of the fallbacks, so the tuple must have the same
+/// arity as the value.
+///
+/// ```text
+/// // Tri Expression
+/// let (x, y) = tri!((a, b) => [(1..=9, 1..=9)] <> 0, 0);
+///
+/// // Expanded Form
+/// let (x, y) = match (a, b) { value @ (1..=9, 1..=9) => value, _ => (0, 0) };
+/// ```
+///
+/// ### Weak References `upgrade`
+///
+/// Starting with `upgrade` calls `upgrade()` on the leading expression,
+/// which turns an `rc::Weak` or `sync::Weak` into an `Option` of the
+/// strong pointer. This reads well for back-references in trees.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(upgrade node.parent => Some[parent] <> return);
+///
+/// // Expanded Form
+/// let Some(parent) = node.parent.upgrade() else { return };
+/// ```
+///
+/// ### Tri-Map `|>`
+///
+/// Pipes the captions through a mapping expression before they're
+/// bound or given back. The mapping runs only on a match, and ends at
+/// the first `<>`, `->`, or `#>`, which handles a miss as usual. A
+/// caption is rebound to the mapped value, so several captions map to
+/// a tuple of the same size.
+///
+/// ```text
+/// // Tri Expression
+/// let doubled = tri!(text.parse() => Ok(n) |> n * 2 <> 0);
+///
+/// // Expanded Form
+/// let doubled = if let Ok(n) = text.parse() { n * 2 } else { 0 };
+/// ```
+///
+/// ### Strict Paths `!`
+///
+/// A path after `!` must cover every value, so it only compiles for an
+/// enum with a single variant and takes no operator. Adding a variant
+/// later, or misspelling a bare variant that would otherwise bind
+/// everything, becomes a compile error instead of a silent fallback.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(token => !Token::Eof);
+///
+/// // Expanded Form
+/// let Token::Eof { .. } = token;
+/// ```
+///
+/// ### JSON Values `json::Object[m]`
+///
+/// `json::String`, `json::Number`, `json::Array`, `json::Object`,
+/// `json::Bool` and `json::Null` stand for the variants of
+/// `serde_json::Value`, for picking through a document without
+/// deserializing it. Other `json::` paths are matched as written.
+/// This requires the `serde_json` feature.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(doc => json::Object[fields] -> "expected object");
+///
+/// // Expanded Form
+/// let Value::Object(fields) = doc else { return Err("expected object") };
+/// ```
+///
+/// ### Tri-Log `?!>`
+///
+/// Falls back like `<>`, but runs the first expression after the
+/// operator before the fallback is taken, which is handy for logging
+/// when a default gets used. The rest of the list is the fallback.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(config => Some[port] ?!> eprintln!("using default"), 80);
+///
+/// // Expanded Form
+/// let port = if let Some(port) = config { port }
+/// else { eprintln!("using default"); 80 };
+/// ```
+///
+/// ### Tri-Default (Warn) `<warn_default "..">`
+///
+/// Falls back to `Default::default()` like `?:`, but logs a warning
+/// with the given message first. The warning goes through `log` with
+/// the `log` feature, and to stderr otherwise. A typed caption pins
+/// down which default is used.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(cfg.timeout => Some[t: u64] <warn_default "using default timeout">);
+///
+/// // Expanded Form
+/// let t = if let Some(t) = cfg.timeout { t }
+/// else { log::warn!("{}", "using default timeout"); Default::default() };
+/// let _: u64 = t;
+/// ```
+///
+/// ### Entry Variants `Entry::Occupied[e]`
+///
+/// The `Entry` enums of the standard maps are matched like any other,
+/// which binds the occupied or vacant entry without a full `match`.
+/// The entry keeps the map borrowed, so a fallback can't reach back
+/// into the map, but it can return or fail.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(map.entry(key) => Entry::Vacant[slot] -> "already present");
+///
+/// // Expanded Form
+/// let Entry::Vacant(slot) = map.entry(key) else { return Err("already present") };
+/// ```
+///
+/// ### Tri-Ok-Or `ok_or>`
+///
+/// An alias for `-?>` with a trailing expression, which reads better
+/// when an `Option` is matched in a function returning `Result`. It's
+/// rewritten to `-?>` before it expands, so the two always agree.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(find_user(id) => Some[user] ok_or> AppError::Missing);
+///
+/// // Expanded Form
+/// tri!(find_user(id) => Some[user] -?> AppError::Missing);
+/// ```
+///
+/// ### Tri-Ok `=>ok`
+///
+/// Turns the match into a `Result`, with the captions in `Ok` and the
+/// trailing expression in `Err`. Since it's a value rather than a
+/// binding, it can be the tail of a function returning `Result`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(text.parse() => Ok[port] =>ok "not a port")
+///
+/// // Expanded Form
+/// match text.parse() { Ok(port) => Ok(port), _ => Err("not a port") }
+/// ```
+///
+/// ### Box Patterns
+///
+/// With the `nightly` feature enabled, a field can be moved out of
+/// a box with `box`. The calling crate must also enable
+/// `#![feature(box_patterns)]`.
+///
+/// ```text
+/// // Tri Expression
+/// tri!(item => Some(box value) <> 0);
+///
+/// // Expanded Form
+/// if let Some(box value) = item { value }
+/// else { 0 }
+/// ```
+///
+/// ### Unused Bindings
+///
+/// Captions are bound with ordinary `let` statements, so a caption
+/// that is never read is reported by `unused_variables` at the
+/// call site.
+///
+/// ```compile_fail
+/// #![deny(unused_variables)]
+/// # use tri_ton::tri;
+/// fn foo(item: Option<u8>) {
+///     tri!(item => Some[value] <> 0);
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ___
+#[macro_export]
+macro_rules! tri {
+    // Label
+    ($lbl:lifetime : $chk:expr => $($tal:tt)+) =>
+    { $crate::__format_label!($lbl $chk => [] $($tal)+) };
+    
+    // Env
+    (env $var:literal => $($tal:tt)+) =>
+    { $crate::tri!($crate::__env_var!($var) => $($tal)+) };
+    
+    // Upgrade
+    (upgrade $wek:expr => $($tal:tt)+) =>
+    { $crate::tri!($wek.upgrade() => $($tal)+) };
+    
+    // Entry
+    ($col:expr, $key:expr => $val:ident <> $dft:expr $(;)?) =>
+    { let $val = { use $crate::entry::OrInsert as _; $col.or_insert_with($key, || $dft) }; };
+    
+    // Raw
+    ($chk:expr => raw!($($rle:tt)+) $($tal:tt)+) =>
+    { $crate::__expand_rule!($chk => [$($rle)+] $($tal)+) };
+    
+    // Each (Caption)
+    ($chk:expr => each $($xpv:ident $(::<$($inr:tt)+>)?)::+[$($uci:tt)+] $($tal:tt)+) =>
+    { $crate::tri!($chk => each $($xpv $(::<$($inr)+>)?)::+($($uci)+) $($tal)+) };
+    
+    // Each
+    ($chk:expr => each $($xpv:ident $(::<$($inr:tt)+>)?)::+($($uci:tt)+) $($tal:tt)+) =>
+    {{
+        let mut __tri_each = $crate::__each_vec!();
+        for __tri_item in $chk {
+            __tri_each.push($crate::tri!(__tri_item => $($xpv $(::<$($inr)+>)?)::+($($uci)+) $($tal)+));
+        }
+        __tri_each
+    }};
+    
+    // Flatten
+    ($chk:expr => flatten $xpv:ident $($tal:tt)+) =>
+    { $crate::tri!(::core::option::Option::flatten($chk) => $xpv $($tal)+) };
+    
+    // Note - Only The Six `Value` Variants Are Taken, So Other `json::` Paths Match As Usual
+    // Json
+    ($chk:expr => json::String $($tal:tt)+) =>
+    { $crate::__json_term!($chk => String $($tal)+) };
+    
+    ($chk:expr => json::Number $($tal:tt)+) =>
+    { $crate::__json_term!($chk => Number $($tal)+) };
+    
+    ($chk:expr => json::Array $($tal:tt)+) =>
+    { $crate::__json_term!($chk => Array $($tal)+) };
+    
+    ($chk:expr => json::Object $($tal:tt)+) =>
+    { $crate::__json_term!($chk => Object $($tal)+) };
+    
+    ($chk:expr => json::Bool $($tal:tt)+) =>
+    { $crate::__json_term!($chk => Bool $($tal)+) };
+    
+    ($chk:expr => json::Null $($tal:tt)+) =>
+    { $crate::__json_term!($chk => Null $($tal)+) };
+    
+    // Caption
+    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+[$($uci:tt)+] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)+] [] [] $($tal)+) };
+    
+    // Variant (Field Fallback)
+    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+($($uci:tt)+)) =>
+    { $crate::__format_fallback!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)+] [] []) };
+    
+    // Variant
+    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+($($uci:tt)+) $($tal:tt)+) =>
+    { $crate::__format_variant!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)+] [] [] $($tal)+) };
+    
+    // Some (Mut)
+    ($chk:expr => some mut $a:ident $($tal:tt)+) =>
+    { $crate::tri!($chk => Some[mut $a] $($tal)+) };
+    
+    // Some
+    ($chk:expr => some $a:ident $($tal:tt)+) =>
+    { $crate::tri!($chk => Some[$a] $($tal)+) };
+    
+    // Note - Paths Starting With `none` Skip The Shorthand
+    // None
+    ($chk:expr => none :: $($tal:tt)+) =>
+    { $crate::__format_path!($chk => [none ::] $($tal)+) };
+    
+    ($chk:expr => none $($tal:tt)+) =>
+    { $crate::tri!($chk => None $($tal)+) };
+    
+    // Struct
+    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+ { $($uci:tt)* } $($tal:tt)+) =>
+    { $crate::__format_struct!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)*] [] [] $($tal)+) };
+    
+    // Note - Braces Keep A Misspelled Bare Variant From Being Read As A Binding
+    // Strict
+    ($chk:expr => ! $($xpv:ident $(::<$($inr:tt)+>)?)::+ $(;)?) =>
+    { let $($xpv $(::<$($inr)+>)?)::+ { .. } = $chk; };
+    
+    // Path
+    ($chk:expr => $xpv:ident $($tal:tt)+) =>
+    { $crate::__format_path!($chk => [] $xpv $($tal)+) };
+    
+    // Note - A Bare Binding Always Matches, So The Fallback Is Only Type Checked
+    // Rule (Typed Mut)
+    ($chk:expr => [mut $rid:ident : $rty:ty] <> $otw:expr $(;)?) =>
+    { let mut $rid: $rty = $chk; let _ = || -> $rty { $otw }; };
+    
+    // Rule (Typed)
+    ($chk:expr => [$rid:ident : $rty:ty] <> $otw:expr $(;)?) =>
+    { let $rid: $rty = $chk; let _ = || -> $rty { $otw }; };
+    
+    // Rule (Typed Mut Operator)
+    ($chk:expr => [mut $rid:ident : $rty:ty] $($tal:tt)+) =>
+    { compile_error!("a typed rule always matches, so it only takes a `<>` fallback") };
+    
+    // Rule (Typed Operator)
+    ($chk:expr => [$rid:ident : $rty:ty] $($tal:tt)+) =>
+    { compile_error!("a typed rule always matches, so it only takes a `<>` fallback") };
+    
+    // Note - Const Generics Can't Be Used In Patterns, So A Lone Range With An Identifier Bound Is Checked With `contains`
+    // Rule (Const Range)
+    ($chk:expr => [$($low:literal)? .. $hgh:ident] $($tal:tt)+) =>
+    { $crate::__expand_range!($chk => [($($low)?..$hgh)] $($tal)+) };
+    
+    // Rule (Const Range Inclusive)
+    ($chk:expr => [$($low:literal)? ..= $hgh:ident] $($tal:tt)+) =>
+    { $crate::__expand_range!($chk => [($($low)?..=$hgh)] $($tal)+) };
+    
+    // Rule (Const Range From)
+    ($chk:expr => [$low:ident .. $($hgh:tt)?] $($tal:tt)+) =>
+    { $crate::__expand_range!($chk => [($low..$($hgh)?)] $($tal)+) };
+    
+    // Rule (Const Range From Inclusive)
+    ($chk:expr => [$low:ident ..= $hgh:tt] $($tal:tt)+) =>
+    { $crate::__expand_range!($chk => [($low..=$hgh)] $($tal)+) };
+    
+    // Rule
+    ($chk:expr => [$($rle:pat),*] $($tal:tt)+) =>
+    { $crate::__expand_rule!($chk => [$($rle),*] $($tal)+) };
+    
+    // Note - Anything Left Over Is Reported Here Rather Than As An Unexpected Token
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
+}
+
+/// ## Tri_Loop! - Loop While Matching ##
+///
+/// The **tri_loop!** macro runs a block for as long as the leading
+/// expression matches the specified term. Captions and rules are
+/// bound for each pass, so it reads like a `while let` loop with
+/// the same binding syntax as **tri!**.
+///
+/// ```text
+/// // Tri Expression
+/// tri_loop!(items.next() => Some[item] { total += item });
+///
+/// // Expanded Form
+/// loop {
+///     let Some(item) = items.next() else { break };
+///     { total += item }
+/// }
+/// ```
+///
+/// ___
+#[macro_export]
+macro_rules! tri_loop {
+    ($chk:expr => $($tal:tt)+) => { $crate::__format_loop!($chk => [] $($tal)+) };
+}
+
+/// ## Tri_All! - Validate Several Values ##
+///
+/// The **tri_all!** macro checks several independent expressions, each
+/// against its own term, and binds every caption into scope. The first
+/// clause that fails returns the shared Tri-Fail error, so the later
+/// clauses are never evaluated.
+///
+/// ```text
+/// // Tri Expression
+/// tri_all!((name => Some[name]), (age.parse() => Ok[age]) -> "validation failed");
+///
+/// // Expanded Form
+/// let Some(name) = name else { return Err("validation failed") };
+/// let Ok(age) = age.parse() else { return Err("validation failed") };
+/// ```
+///
+/// ___
+#[macro_export]
+macro_rules! tri_all {
+    ($(($($cls:tt)+)),+ $(,)? -> $($err:tt)+) => { $crate::__format_all!([$($err)+] $(($($cls)+))+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_all {
+    // Output
+    ([$($err:tt)+]) => {};
+    
+    // Clause
+    ([$($err:tt)+] ($($cls:tt)+) $($tal:tt)*) =>
+    { $crate::tri!($($cls)+ -> $($err)+); $crate::__format_all!([$($err)+] $($tal)*); };
+}
+
+/// ## Tri_Else! - Act On A Match ##
+///
+/// The **tri_else!** macro turns the branches of a tri expression
+/// around. The trailing expression is run only when the term matches,
+/// with its captions in scope, and nothing happens otherwise. It takes
+/// the same terms as `tri!` with `->`, `#>`, and `<>`.
 ///
-///     // Tri Expression
-///     tri!(item => Some(value) %> thing += 1);
+/// ```text
+/// // Tri Expression
+/// tri_else!(response => Err(e) -> log_and_wrap(e));
 ///
-///     // Expanded Form
-///     loop {
-///         if let Some(value) = item { break value; }
-///         else { thing += 1; }
-///     }
+/// // Expanded Form
+/// if let Err(e) = response { return Err(log_and_wrap(e)) }
+/// ```
 ///
-/// ### Tri-While `>>`
+/// ___
+#[macro_export]
+macro_rules! tri_else {
+    ($chk:expr => $($tal:tt)+) => { $crate::__format_else!($chk => [] $($tal)+) };
+}
+
+// Note - The Operator Is Marked With `else` So The Expanders Know To Turn The Branches Around
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_else {
+    // Tri-Fail
+    ($chk:expr => [$($trm:tt)+] -> $($tal:tt)+) =>
+    { $crate::tri!($chk => $($trm)+ else -> $($tal)+) };
+    
+    // Tri-Return
+    ($chk:expr => [$($trm:tt)+] #> $($tal:tt)+) =>
+    { $crate::tri!($chk => $($trm)+ else #> $($tal)+) };
+    
+    // Tri-Fall
+    ($chk:expr => [$($trm:tt)+] <> $($tal:tt)+) =>
+    { $crate::tri!($chk => $($trm)+ else <> $($tal)+) };
+    
+    // Token
+    ($chk:expr => [$($trm:tt)*] $nxt:tt $($tal:tt)+) =>
+    { $crate::__format_else!($chk => [$($trm)* $nxt] $($tal)+) };
+}
+
+/// ## Tri_Cmp! - Compare Several Keys ##
 ///
-/// This operator acts like a `do-while` loop. The values are
-/// initialized with the given expressions, and the trailing
-/// expression is evaluated. The leading expression is then
-/// evaluated in a loop. For every time that the output matches
-/// the given variant, the trailing expression is evaluated with
-/// those values.
+/// The **tri_cmp!** macro compares pairs of keys in order and returns
+/// the first `Ordering` that isn't `Equal`, or `Equal` if every pair
+/// ties. Later pairs are only evaluated on a tie, which suits `Ord`
+/// impls and `sort_by` closures with tie-breaks.
 ///
-///     // Tri Expression
-///     tri!(do_stuff(number) => Some(value = 0) >> number += value);
+/// ```text
+/// // Tri Expression
+/// tri_cmp!(a.major, b.major; a.minor, b.minor)
 ///
-///     // Expanded Form (Pseudo-Code)
-///     do(value = 0) { number += value; }
-///     while let Some(value) = do_stuff(number);
+/// // Expanded Form
+/// Ord::cmp(&a.major, &b.major).then_with(|| Ord::cmp(&a.minor, &b.minor))
+/// ```
 ///
 /// ___
 #[macro_export]
-macro_rules! tri {
-    // Caption
-    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+[$($uci:tt)+ $(,)?] $($tal:tt)+) =>
-    { $crate::__format_caption!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)+] [] [] $($tal)+); };
+macro_rules! tri_cmp {
+    ($lhs:expr, $rhs:expr $(; $nlh:expr, $nrh:expr)* $(;)?) =>
+    { ::core::cmp::Ord::cmp(&$lhs, &$rhs)$(.then_with(|| ::core::cmp::Ord::cmp(&$nlh, &$nrh)))* };
+}
+
+// Note - The Block Is Split Off One Token At A Time Since A Term Can End In Braces
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_loop {
+    // Output
+    ($chk:expr => [$($trm:tt)+] { $($blk:tt)* }) =>
+    { loop { $crate::tri!($chk => $($trm)+ <> break); { $($blk)* } } };
     
-    // Variant
-    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+($($uci:tt)+ $(,)?) $($tal:tt)+) =>
-    { $crate::__format_variant!($chk => $($xpv $(::<$($inr)+>)?)::+ [$($uci)+] [] [] $($tal)+) };
+    // Token
+    ($chk:expr => [$($trm:tt)*] $nxt:tt $($tal:tt)+) =>
+    { $crate::__format_loop!($chk => [$($trm)* $nxt] $($tal)+) };
+}
+
+// Note - The Label Is Moved Behind The Loop Operator, Which Is The First `%>` Or `>>` Outside A Group
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_label {
+    // Tri-Until
+    ($lbl:lifetime $chk:expr => [$($trm:tt)+] %> $($tal:tt)+) =>
+    { $crate::tri!($chk => $($trm)+ %> $lbl: $($tal)+) };
     
-    // Path
-    ($chk:expr => $($xpv:ident $(::<$($inr:tt)+>)?)::+ $($tal:tt)+) =>
-    { $crate::__expand_path!($chk => $($xpv $(::<$($inr)+>)?)::+ [] $($tal)+); };
+    // Tri-While
+    ($lbl:lifetime $chk:expr => [$($trm:tt)+] >> $($tal:tt)+) =>
+    { $crate::tri!($chk => $($trm)+ >> $lbl: $($tal)+) };
     
-    // Rule
-    ($chk:expr => [$($rle:pat),*] $($tal:tt)+) =>
-    { $crate::__expand_rule!($chk => [$($rle),*] $($tal)+); };
+    // Token
+    ($lbl:lifetime $chk:expr => [$($trm:tt)*] $nxt:tt $($tal:tt)+) =>
+    { $crate::__format_label!($lbl $chk => [$($trm)* $nxt] $($tal)+) };
+}
+
+// Note - Splitting Paths Here Avoids Ambiguity Between `::` And The Operator
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_path {
+    // Segment (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> :: $($tal:tt)+) =>
+    { $crate::__format_path!($chk => [$($seg)* $nxt ::<$($inr)+> ::] $($tal)+) };
+    
+    // Segment
+    ($chk:expr => [$($seg:tt)*] $nxt:ident :: $($tal:tt)+) =>
+    { $crate::__format_path!($chk => [$($seg)* $nxt ::] $($tal)+) };
+    
+    // Or (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> | $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt ::<$($inr)+> |] $($tal)+) };
+    
+    // Or
+    ($chk:expr => [$($seg:tt)*] $nxt:ident | $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt |] $($tal)+) };
+    
+    // Output (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> $($tal:tt)+) =>
+    { $crate::__expand_path!($chk => $($seg)* $nxt ::<$($inr)+> [] $($tal)+) };
+    
+    // Output
+    ($chk:expr => [$($seg:tt)*] $nxt:ident $($tal:tt)+) =>
+    { $crate::__expand_path!($chk => $($seg)* $nxt [] $($tal)+) };
+}
+
+// Note - Paths Joined By `|` Are Passed On As A Single Rule
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_or {
+    // Segment (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> :: $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt ::<$($inr)+> ::] $($tal)+) };
+    
+    // Segment
+    ($chk:expr => [$($seg:tt)*] $nxt:ident :: $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt ::] $($tal)+) };
+    
+    // Or (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> | $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt ::<$($inr)+> |] $($tal)+) };
+    
+    // Or
+    ($chk:expr => [$($seg:tt)*] $nxt:ident | $($tal:tt)+) =>
+    { $crate::__format_or!($chk => [$($seg)* $nxt |] $($tal)+) };
+    
+    // Output (Generic)
+    ($chk:expr => [$($seg:tt)*] $nxt:ident ::<$($inr:tt)+> $($tal:tt)+) =>
+    { $crate::__expand_rule!($chk => [$($seg)* $nxt ::<$($inr)+>] $($tal)+) };
+    
+    // Output
+    ($chk:expr => [$($seg:tt)*] $nxt:ident $($tal:tt)+) =>
+    { $crate::__expand_rule!($chk => [$($seg)* $nxt] $($tal)+) };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __format_caption {
+    // Note - The Inner Pattern Already Borrows, So The Outer Binding Is Left Plain
     // Ref Mut
     (
         $chk:expr => $xpv:path
-        [ref mut $a:ident $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [ref mut $a:tt $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_caption! {
             $chk => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                ref, mut, $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                ref, mut, [$a] $(@ $b)? $(= $c)?,
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* ref mut # $a $(= $c)?]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
             $($tal)+
         }
     };
@@ -138,161 +1727,549 @@ macro_rules! __format_caption {
     // Ref
     (
         $chk:expr => $xpv:path
-        [ref $a:ident $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [ref $a:tt $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_caption! {
             $chk => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                ref, , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                ref, , [$a] $(@ $b)? $(= $c)?,
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* ref # $a $(= $c)?]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
             $($tal)+
         }
     };
     
     // Note - Removed Mut From First Sequence On Its Own
+    // Note - Annotations Are Checked After The Term, So They Only Work With Statement Operators
     // Mut
     (
         $chk:expr => $xpv:path
-        [mut $a:ident $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [mut $a:tt $(: $(& $($lft:lifetime)?)? $($aty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_caption! {
             $chk => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                , , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a] $(@ $b)? $(= $c)?,
             ]
             [$($($bmo)* # $cln $(= $ani)?,)* mut # $a $(= $c)?]
             $($tal)+
         }
+        $(let _: $(& $($lft)?)? $($aty $(::<$($owo)+>)?)::+ = $a;)?
+    };
+    
+    // Box
+    (
+        $chk:expr => $xpv:path
+        [box $a:tt $(@ $b:pat)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_box! {
+            __format_caption!
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                box, , [$a] $(@ $b)?,
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a]
+            $($tal)+
+        }
+    };
+    
+    // Note - The Pointer Is Rebound After The Term, So This Only Works With Statement Operators
+    // Deref Unsafe
+    (
+        $chk:expr => $xpv:path
+        [deref_unsafe $a:ident $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_caption! {
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a],
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a]
+            $($tal)+
+        }
+        let $a = unsafe { ::core::ptr::NonNull::as_ref(&$a) };
+    };
+    
+    // Note - Field Guards Are Moved Behind The Term And Joined With Any Other Guard
+    // Field Guard
+    ($chk:expr => $xpv:path [$a:ident $(@ $b:pat)? if $($uci:tt)+] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__field_guard!(__format_caption $chk => $xpv [$a $(@ $b)?] [$($uci)+] [] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - A Tuple Of Plain Identifiers Is One Capture, So Its Fallback Is One Tuple
+    // Nested Tuple
+    (
+        $chk:expr => $xpv:path
+        [($fst:ident, $($nst:ident),+ $(,)?) $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_caption! {
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [($fst, $($nst),+)],
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # ($fst, $($nst),+)]
+            $($tal)+
+        }
+    };
+    
+    // Note - Tokens That Can't Be Bound Are Sent To $pat
+    // Non-Binding
+    ($chk:expr => $xpv:path [_ $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [% _ $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [.. $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [% .. $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [$lit:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [% $lit $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [($($grp:tt)*) $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [% ($($grp)*) $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [[$($grp:tt)*] $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [% [$($grp)*] $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - Boolean Literals Match $ident, So They Are Marked Before A Batch Can Take Them
+    // Batch Break
+    ($chk:expr => $xpv:path [$a:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [$a, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [$a:ident, $b:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [$a, $b, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chk:expr => $xpv:path [$a:ident, $b:ident, $c:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_caption!($chk => $xpv [$a, $b, $c, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - Runs Of Plain Identifiers Are Formatted Four At A Time To Save Recursion Depth
+    // Batch
+    (
+        $chk:expr => $xpv:path
+        [$a:ident, $b:ident, $c:ident, $d:ident $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_caption! {
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a], , , , [$b], , , , [$c], , , , [$d],
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a, # $b, # $c, # $d]
+            $($tal)+
+        }
+    };
+    
+    // Note - Only Plain Identifiers Can Be Bound Inside A Nested Caption
+    // Nested Caption
+    (
+        $chk:expr => $xpv:path
+        [$($ipv:ident)::+ [$($nst:ident),+] $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_caption! {
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , , $($ipv)::+($($nst),+)
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* $(# $nst),+]
+            $($tal)+
+        }
     };
     
     // $ident
     (
         $chk:expr => $xpv:path
-        [$a:ident $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$a:tt $(: $(& $($lft:lifetime)?)? $($aty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_caption! {
             $chk => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                , , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a] $(@ $b)? $(= $c)?,
             ]
             [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
             $($tal)+
         }
+        $(let _: $(& $($lft)?)? $($aty $(::<$($owo)+>)?)::+ = $a;)?
     };
     
     // $pat
     (
         $chk:expr => $xpv:path
-        [$wut:pat $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$(%)? $wut:pat $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_caption! {
+            $chk => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , , $wut
+            ]
+            [$($($bmo)* # $cln $(= $ani)?),*]
+            $($tal)+
+        }
+    };
+    
+    // Output
+    (
+        $chk:expr => $xpv:path
+        []
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__caption_suffix! {
+            [
+                $chk => $xpv
+                [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?),*]
+                [$($($bmo)* # $cln $(= $ani)?),*]
+            ]
+            []
+            $($tal)+
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_fallback {
+    // Field
+    ($chc:expr => $xpv:path [<> $dft:expr $(, $($uci:tt)*)?] [$($fld:tt)*] [$($cur:tt)+]) =>
+    { $crate::__format_fallback!($chc => $xpv [$($($uci)*)?] [$($fld)* [$($cur)+] $dft,] []) };
+    
+    // Token
+    ($chc:expr => $xpv:path [$nxt:tt $($uci:tt)*] [$($fld:tt)*] [$($cur:tt)*]) =>
+    { $crate::__format_fallback!($chc => $xpv [$($uci)*] [$($fld)*] [$($cur)* $nxt]) };
+    
+    // Output
+    ($chc:expr => $xpv:path [] [$([$a:ident $(@ $grd:pat)?] $dft:expr,)+] []) =>
+    {{
+        #[allow(irrefutable_let_patterns)]
+        let __tri_fields = match $chc {
+            $xpv($($a),+) => ($(if let $a $(@ $grd)? = $a { $a } else { $dft }),+),
+            #[allow(unreachable_patterns)]
+            _ => ($($dft),+),
+        };
+        __tri_fields
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_struct {
+    // Rest
+    ($chc:expr => $xpv:path [..] [$($fld:tt)*] [$($bnd:ident)*] $($tal:tt)+) =>
+    { $crate::__expand_struct!($chc => $xpv { $($fld)* .. } [$($bnd)*] $($tal)+) };
+    
+    // Rename
+    ($chc:expr => $xpv:path [$a:ident : $b:ident $(, $($uci:tt)*)?] [$($fld:tt)*] [$($bnd:ident)*] $($tal:tt)+) =>
+    { $crate::__format_struct!($chc => $xpv [$($($uci)*)?] [$($fld)* $a: $b,] [$($bnd)* $b] $($tal)+) };
+    
+    // Pattern
+    ($chc:expr => $xpv:path [$a:ident : $b:pat $(, $($uci:tt)*)?] [$($fld:tt)*] [$($bnd:ident)*] $($tal:tt)+) =>
+    { $crate::__format_struct!($chc => $xpv [$($($uci)*)?] [$($fld)* $a: $b,] [$($bnd)*] $($tal)+) };
+    
+    // $ident
+    ($chc:expr => $xpv:path [$a:ident $(, $($uci:tt)*)?] [$($fld:tt)*] [$($bnd:ident)*] $($tal:tt)+) =>
+    { $crate::__format_struct!($chc => $xpv [$($($uci)*)?] [$($fld)* $a,] [$($bnd)* $a] $($tal)+) };
+    
+    // Output
+    ($chc:expr => $xpv:path [] [$($fld:tt)*] [$($bnd:ident)*] $($tal:tt)+) =>
+    { $crate::__expand_struct!($chc => $xpv { $($fld)* } [$($bnd)*] $($tal)+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __caption_suffix {
+    // Guard
+    ([$($hed:tt)*] [] if $($rst:tt)+) =>
+    { $crate::__format_guard! { __expand_caption [$($hed)*] [] $($rst)+ } };
+    
+    // Struct
+    ([$($hed:tt)*] [$($tal:tt)*] as $($sty:ident)::+) =>
+    { $crate::__expand_caption! { $($hed)* as $($sty)::+, $($tal)* } };
+    
+    // Token
+    ([$($hed:tt)*] [$($tal:tt)*] $nxt:tt $($rst:tt)*) =>
+    { $crate::__caption_suffix! { [$($hed)*] [$($tal)* $nxt] $($rst)* } };
+    
+    // Output
+    ([$($hed:tt)*] [$($tal:tt)*]) =>
+    { $crate::__expand_caption! { $($hed)* $($tal)* } };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __field_guard {
+    // Joined
+    ($mac:ident $chk:expr => $xpv:path [$($fld:tt)+] [$(, $($uci:tt)*)?] [$($cnd:tt)+] [$($lst:tt)*] [$($cln:tt)*] if $($tal:tt)+) =>
+    { $crate::$mac!($chk => $xpv [$($fld)+ $(, $($uci)*)?] [$($lst)*] [$($cln)*] if ($($cnd)+) && $($tal)+) };
+    
+    // Output
+    ($mac:ident $chk:expr => $xpv:path [$($fld:tt)+] [$(, $($uci:tt)*)?] [$($cnd:tt)+] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::$mac!($chk => $xpv [$($fld)+ $(, $($uci)*)?] [$($lst)*] [$($cln)*] if ($($cnd)+) $($tal)+) };
+    
+    // Token
+    ($mac:ident $chk:expr => $xpv:path [$($fld:tt)+] [$nxt:tt $($uci:tt)*] [$($cnd:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__field_guard!($mac $chk => $xpv [$($fld)+] [$($uci)*] [$($cnd)* $nxt] [$($lst)*] [$($cln)*] $($tal)+) };
+}
+
+// Note - The Mapping Is Split Off At The First Fallback Operator, Since An Expression Can't Be Followed By One
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_map {
+    // Tri-Fail
+    ($mac:ident [$($hed:tt)*] [$($map:tt)+] -> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* |> % [$($map)+] -> $($tal)+ } };
+    
+    // Tri-Fall
+    ($mac:ident [$($hed:tt)*] [$($map:tt)+] <> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* |> % [$($map)+] <> $($tal)+ } };
+    
+    // Tri-Return
+    ($mac:ident [$($hed:tt)*] [$($map:tt)+] #> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* |> % [$($map)+] #> $($tal)+ } };
+    
+    // Token
+    ($mac:ident [$($hed:tt)*] [$($map:tt)*] $nxt:tt $($tal:tt)*) =>
+    { $crate::__format_map! { $mac [$($hed)*] [$($map)* $nxt] $($tal)* } };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_guard {
+    // Tri-Fail
+    ($mac:ident [$($hed:tt)*] [$($cnd:tt)+] -> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* if [$($cnd)+] -> $($tal)+ } };
+    
+    // Tri-Fall
+    ($mac:ident [$($hed:tt)*] [$($cnd:tt)+] <> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* if [$($cnd)+] <> $($tal)+ } };
+    
+    // Tri-Return
+    ($mac:ident [$($hed:tt)*] [$($cnd:tt)+] #> $($tal:tt)+) =>
+    { $crate::$mac! { $($hed)* if [$($cnd)+] #> $($tal)+ } };
+    
+    // Token
+    ($mac:ident [$($hed:tt)*] [$($cnd:tt)*] $nxt:tt $($tal:tt)*) =>
+    { $crate::__format_guard! { $mac [$($hed)*] [$($cnd)* $nxt] $($tal)* } };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __format_variant {
+    // Note - The Inner Pattern Already Borrows, So The Outer Binding Is Left Plain
+    // Ref Mut
+    (
+        $chc:expr => $xpv:path
+        [ref mut $a:tt $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_variant! {
+            $chc => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                ref, mut, [$a] $(@ $b)? $(= $c)?,
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
+            $($tal)+
+        }
+    };
+    
+    // Ref
+    (
+        $chc:expr => $xpv:path
+        [ref $a:tt $(: $(&)? $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        $($tal:tt)+
+    ) => {
+        $crate::__format_variant! {
+            $chc => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                ref, , [$a] $(@ $b)? $(= $c)?,
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
+            $($tal)+
+        }
+    };
+    
+    // Note - Removed Mut From First Sequence
+    // Mut
+    (
+        $chc:expr => $xpv:path
+        [mut $a:tt $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
-        $crate::__format_caption! {
-            $chk => $xpv
-            [$($($uci)+)?]
+        $crate::__format_variant! {
+            $chc => $xpv
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                , , , $wut
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a] $(@ $b)? $(= $c)?,
             ]
-            [$($($bmo)* # $cln $(= $ani)?),*]
+            [$($($bmo)* # $cln $(= $ani)?,)* mut # $a $(= $c)?]
             $($tal)+
         }
     };
     
-    // Output
+    // Box
     (
-        $chk:expr => $xpv:path
-        []
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        $chc:expr => $xpv:path
+        [box $a:tt $(@ $b:pat)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
-        $crate::__expand_caption! {
-            $chk => $xpv
-            [$($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?),*]
-            [$($($bmo)* # $cln $(= $ani)?),*]
+        $crate::__format_box! {
+            __format_variant!
+            $chc => $xpv
+            [$($($uci)*)?]
+            [
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                box, , [$a] $(@ $b)?,
+            ]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a]
             $($tal)+
         }
     };
-}
-
-#[doc(hidden)]
-#[macro_export]
-macro_rules! __format_variant {
-    // Ref Mut
+    
+    // Note - Field Guards Are Moved Behind The Term And Joined With Any Other Guard
+    // Field Guard
+    ($chc:expr => $xpv:path [$a:ident $(@ $b:pat)? if $($uci:tt)+] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__field_guard!(__format_variant $chc => $xpv [$a $(@ $b)?] [$($uci)+] [] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - A Tuple Of Plain Identifiers Is One Capture, So Its Fallback Is One Tuple
+    // Nested Tuple
     (
         $chc:expr => $xpv:path
-        [ref mut $a:ident $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [($fst:ident, $($nst:ident),+ $(,)?) $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_variant! {
             $chc => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                ref, mut, $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [($fst, $($nst),+)],
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* ref mut # $a]
+            [$($($bmo)* # $cln $(= $ani)?,)* # ($fst, $($nst),+)]
             $($tal)+
         }
     };
     
-    // Ref
+    // Note - Tokens That Can't Be Bound Are Sent To $pat
+    // Non-Binding
+    ($chc:expr => $xpv:path [_ $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [% _ $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [.. $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [% .. $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [$lit:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [% $lit $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [($($grp:tt)*) $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [% ($($grp)*) $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [[$($grp:tt)*] $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [% [$($grp)*] $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - Boolean Literals Match $ident, So They Are Marked Before A Batch Can Take Them
+    // Batch Break
+    ($chc:expr => $xpv:path [$a:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [$a, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [$a:ident, $b:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [$a, $b, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    ($chc:expr => $xpv:path [$a:ident, $b:ident, $c:ident, $l:literal $($uci:tt)*] [$($lst:tt)*] [$($cln:tt)*] $($tal:tt)+) =>
+    { $crate::__format_variant!($chc => $xpv [$a, $b, $c, % $l $($uci)*] [$($lst)*] [$($cln)*] $($tal)+) };
+    
+    // Note - Runs Of Plain Identifiers Are Formatted Four At A Time To Save Recursion Depth
+    // Batch
     (
         $chc:expr => $xpv:path
-        [ref $a:ident $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$a:ident, $b:ident, $c:ident, $d:ident $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_variant! {
             $chc => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                ref, , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a], , , , [$b], , , , [$c], , , , [$d],
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* ref # $a]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a, # $b, # $c, # $d]
             $($tal)+
         }
     };
     
-    // Note - Removed Mut From First Sequence
-    // Mut
+    // Note - Only Plain Identifiers Can Be Bound Inside A Nested Caption
+    // Nested Caption
     (
         $chc:expr => $xpv:path
-        [mut $a:ident $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$($ipv:ident)::+ [$($nst:ident),+] $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_variant! {
             $chc => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                , , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , , $($ipv)::+($($nst),+)
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* mut # $a]
+            [$($($bmo)* # $cln $(= $ani)?,)* $(# $nst),+]
             $($tal)+
         }
     };
@@ -300,19 +2277,19 @@ macro_rules! __format_variant {
     // $ident
     (
         $chc:expr => $xpv:path
-        [$a:ident $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$a:tt $(: $($_ty:ident $(::<$($owo:tt)+>)?)::+)? $(@ $b:pat)? $(= $c:expr)? $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_variant! {
             $chc => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
-                , , $a $(@ $b)? $(= $c)?,
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                , , [$a] $(@ $b)? $(= $c)?,
             ]
-            [$($($bmo)* # $cln $(= $ani)?,)* # $a]
+            [$($($bmo)* # $cln $(= $ani)?,)* # $a $(= $c)?]
             $($tal)+
         }
     };
@@ -320,16 +2297,16 @@ macro_rules! __format_variant {
     // $pat
     (
         $chc:expr => $xpv:path
-        [$wut:pat $(, $($uci:tt)+)?]
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$(%)? $wut:pat $(, $($uci:tt)*)?]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__format_variant! {
             $chc => $xpv
-            [$($($uci)+)?]
+            [$($($uci)*)?]
             [
-                $($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
+                $($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?,)*
                 , , , $wut
             ]
             [$($($bmo)* # $cln $(= $ani)?),*]
@@ -337,151 +2314,1484 @@ macro_rules! __format_variant {
         }
     };
     
+    // Output (Guard)
+    (
+        $chc:expr => $xpv:path
+        []
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
+        if $($tal:tt)+
+    ) => {
+        $crate::__format_guard! {
+            __expand_variant
+            [
+                $chc => $xpv
+                [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?),*]
+                [$($($bmo)* # $cln $(= $ani)?),*]
+            ]
+            []
+            $($tal)+
+        }
+    };
+    
     // Output
     (
         $chc:expr => $xpv:path
         []
-        [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
-        [$($($bmo:ident)* # $cln:ident $(= $ani:expr)?),*]
+        [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? $(= $ini:expr)?)?, $($alt:pat)?),*]
+        [$($($bmo:ident)* # $cln:tt $(= $ani:expr)?),*]
         $($tal:tt)+
     ) => {
         $crate::__expand_variant! {
             $chc => $xpv
-            [$($($rfi)?, $($mti)?, $($var $(@ $grd)? $(= $ini)?)?, $($alt)?),*]
+            [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)? $(= $ini)?)?, $($alt)?),*]
             [$($($bmo)* # $cln $(= $ani)?),*]
             $($tal)+
         }
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __scalar {
+    // Note - A Single Capture Is Bound On Its Own Rather Than In Parentheses
+    // Scalar
+    ([$($bmo:ident)*] $cln:tt) => { $($bmo)* $cln };
+    
+    // Tuple
+    ($([$($bmo:ident)*] $cln:tt),*) => { ($($($bmo)* $cln),*) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __malformed {
+    () => {
+        compile_error!(concat!(
+            "malformed tri! expression, expected `tri!(value => Term <operator> expression)`\n",
+            "  operators: `<>` fallback, `->` error, `#>` return, `%>` until, `>>` while\n",
+            "  example: `tri!(map.get(key) => Some[value] <> &0)`",
+        ))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "nightly")]
+macro_rules! __format_box {
+    ($fmt:ident! $($tal:tt)+) => { $crate::$fmt! { $($tal)+ } };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "nightly"))]
+macro_rules! __format_box {
+    ($($tal:tt)+) => { compile_error!("box patterns require the `nightly` feature of tri_ton"); };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! __exit_code {
+    ($otw:expr) => { return ::core::convert::Into::<::std::process::ExitCode>::into($otw) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "std"))]
+macro_rules! __exit_code {
+    ($otw:expr) => { compile_error!("the `exit>` operator requires the `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! __box_error {
+    ($otw:expr) => { ::std::boxed::Box::<dyn ::std::error::Error + ::core::marker::Send + ::core::marker::Sync>::from($otw) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "std"))]
+macro_rules! __box_error {
+    ($otw:expr) => { compile_error!("the `->box` operator requires the `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! __emit_value {
+    ($($txp:ident).+, $val:expr) => { ::core::result::Result::is_ok(&$($txp).+.send($val)) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "std"))]
+macro_rules! __emit_value {
+    ($($txp:ident).+, $val:expr) => { compile_error!("the `emit>` operator requires the `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "rand")]
+macro_rules! __jitter_sleep {
+    ($att:ident, $rng:expr, $base:expr, $fct:expr) => {
+        ::std::thread::sleep($crate::jitter::delay($rng, $base, $fct, $att))
+    };
+    
+    ($att:ident, $rng:expr, $base:expr, $fct:expr, $slp:expr) => {
+        ($slp)($crate::jitter::delay($rng, $base, $fct, $att))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "rand"))]
+macro_rules! __jitter_sleep {
+    ($($tal:tt)*) => { compile_error!("the `%>jitter` operator requires the `rand` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "std")]
+macro_rules! __env_var {
+    ($var:literal) => { ::std::env::var($var) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "std"))]
+macro_rules! __env_var {
+    ($($tal:tt)*) => { compile_error!("the `env` leading form requires the `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "log")]
+macro_rules! __log_error {
+    ($chk:expr, $err:expr) => { $crate::__log::error!("{} failed: {:?}", ::core::stringify!($chk), $err) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(not(feature = "log"), feature = "std"))]
+macro_rules! __log_error {
+    ($chk:expr, $err:expr) => { ::std::eprintln!("{} failed: {:?}", ::core::stringify!($chk), $err) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(any(feature = "log", feature = "std")))]
+macro_rules! __log_error {
+    ($($tal:tt)*) => { compile_error!("the `->log` operator requires the `log` or `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "log")]
+macro_rules! __warn_default {
+    ($msg:literal) => { $crate::__log::warn!("{}", $msg) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(not(feature = "log"), feature = "std"))]
+macro_rules! __warn_default {
+    ($msg:literal) => { ::std::eprintln!("warning: {}", $msg) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(any(feature = "log", feature = "std")))]
+macro_rules! __warn_default {
+    ($($tal:tt)*) => { compile_error!("the `<warn_default>` operator requires the `log` or `std` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "tracing")]
+macro_rules! __trace_error {
+    ($msg:literal $(, $($fld:tt)+)?) => { $crate::__tracing::error!($($($fld)+,)? $msg) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "tracing"))]
+macro_rules! __trace_error {
+    ($($tal:tt)*) => { compile_error!("the `->span` operator requires the `tracing` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "alloc")]
+macro_rules! __located_error {
+    ($msg:literal) => { $crate::located::LocatedError::new(::core::concat!(::core::file!(), ":", ::core::line!()), $msg) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "alloc"))]
+macro_rules! __located_error {
+    ($($tal:tt)*) => { compile_error!("the `->loc` operator requires the `alloc` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "serde_json")]
+macro_rules! __json_term {
+    ($chk:expr => $var:ident $($tal:tt)+) => { $crate::tri!($chk => $crate::__serde_json::Value::$var $($tal)+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "serde_json"))]
+macro_rules! __json_term {
+    ($($tal:tt)*) => { compile_error!("the `json::` terms require the `serde_json` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "tonic")]
+macro_rules! __status_error {
+    ($code:expr, $msg:expr) => { $crate::__tonic::Status::new($code, $msg) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "tonic"))]
+macro_rules! __status_error {
+    ($($tal:tt)*) => { compile_error!("the `status>` operator requires the `tonic` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "http")]
+macro_rules! __http_response {
+    ($code:expr, $body:expr) => {{
+        let mut __tri_response = $crate::__http::Response::new(::core::convert::Into::into($body));
+        *__tri_response.status_mut() = $code;
+        __tri_response
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "http"))]
+macro_rules! __http_response {
+    ($($tal:tt)*) => { compile_error!("the `http>` operator requires the `http` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "futures")]
+macro_rules! __ready_future {
+    ($val:expr) => { $crate::__futures::future::ready($val) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "futures"))]
+macro_rules! __ready_future {
+    ($($tal:tt)*) => { compile_error!("the `future>` operator requires the `futures` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "nightly")]
+macro_rules! __yield_value {
+    ($otw:expr) => { yield $otw };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "nightly"))]
+macro_rules! __yield_value {
+    ($otw:expr) => { compile_error!("the `yield>` operator requires the `nightly` feature of tri_ton") };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "alloc")]
+macro_rules! __each_vec {
+    () => { $crate::__alloc::vec::Vec::new() };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "alloc"))]
+macro_rules! __each_vec {
+    () => { compile_error!("the `each` form requires the `alloc` feature of tri_ton") };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __expand_caption {
+    // Tri-Fail (Struct)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] as $sty:path, -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $sty { $($cln),* } } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Fall (Else)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <else $err:ident> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err($err) => $otw }; };
+    
+    // Tri-Fall (Struct)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] as $sty:path, <> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $sty { $($cln),* } } else { $otw } };
+    
+    // Tri-Return (Struct)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] as $sty:path, #> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $sty { $($cln),* } } else { return $otw } };
+    
+    // Note - A Path Fragment Only Starts A Struct Literal At The Head Of A Block
+    // Tri-Option (Struct)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] as $sty:path, opt> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { ::core::option::Option::Some({ $sty { $($cln),* } }) } else { ::core::option::Option::None } };
+    
+    // Tri-Until (Struct)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] as $sty:path, %> $otw:expr $(;)?) =>
+    { loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $sty { $($cln),* } } $otw; } };
+    
+    // Tri-Fail (Guard)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] -> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => return ::core::result::Result::Err($otw) }; };
+    
+    // Tri-Fall (Retries)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cnt:expr]<> $($otw:expr),+ $(,)? $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $crate::__scalar!($([] $cln),*) }
+            if __tri_tries >= $cnt { break ($($otw),+) }
+            __tri_tries += 1;
+        };
+    };
+    
+    // Tri-Map (Fall)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $($map)+ } else { ($($otw),+) }; };
+    
+    // Tri-Map (Fail)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] -> $otw:expr $(;)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $($map)+ } else { return ::core::result::Result::Err($otw) }; };
+    
+    // Tri-Map (Return)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] #> $otw:expr $(;)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $($map)+ } else { return $otw }; };
+    
+    // Tri-Map
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> $($tal:tt)+) =>
+    { $crate::__format_map! { __expand_caption [$chk => $xpv [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)?)?, $($alt)?),*] [$($($bmo)* # $cln),*]] [] $($tal)+ } };
+    
+    // Tri-Fall (Guard)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => ($($otw),+) }; };
+    
+    // Tri-Return (Guard)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] #> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => return $otw }; };
+    
+    // Tri-Fail (Tap)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] tap($tap:expr) -> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { let __tri_caps = $crate::__scalar!($([] $cln),*); ($tap)(&__tri_caps); __tri_caps } else { return ::core::result::Result::Err($otw) }; };
+    
+    // Tri-Fall (Tap)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] tap($tap:expr) <> $($otw:expr $(;)?),+ $(,)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { let __tri_caps = $crate::__scalar!($([] $cln),*); ($tap)(&__tri_caps); __tri_caps } else { ($($otw),+) }; };
+    
+    // Tri-While (Count)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] >>count $cnt:ident $(, $inc:expr)? $(;)?) =>
+    {
+        let mut $cnt: usize = 0;
+        loop {
+            let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { break };
+            $cnt += 1;
+            $($inc;)?
+        }
+    };
+    
+    // Tri-While (Label)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt = $ani:expr),*] >> $lbl:lifetime : $inc:expr $(;)?) =>
+    {
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = {
+            let mut __loop_monitor_dont_use_this_variable_please = ($($ani),*);
+            $lbl: loop {
+                let $crate::__scalar!($([$($bmo)*] $cln),+) = __loop_monitor_dont_use_this_variable_please;
+                $inc;
+                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chk else { break $crate::__scalar!($([] $cln),*) };
+                __loop_monitor_dont_use_this_variable_please = $crate::__scalar!($([] $cln),*);
+            }
+        };
+    };
+    
     // Tri-While
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident = $ani:expr),*] >> $inc:expr $(;)?) =>
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt = $ani:expr),*] >> $inc:expr $(;)?) =>
+    {
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = {
+            let mut __loop_monitor_dont_use_this_variable_please = ($($ani),*);
+            loop {
+                let $crate::__scalar!($([$($bmo)*] $cln),+) = __loop_monitor_dont_use_this_variable_please;
+                $inc;
+                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chk else { break $crate::__scalar!($([] $cln),*) };
+                __loop_monitor_dont_use_this_variable_please = $crate::__scalar!($([] $cln),*);
+            }
+        };
+    };
+    
+    // Tri-Fail (Log)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> log $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), __tri_miss => { let __tri_err = $crate::term::LogTerm::into_error(__tri_miss); $crate::__log_error!($chk, __tri_err); return ::core::result::Result::Err(__tri_err) } }; };
+    
+    // Tri-Fail (Span)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> span($msg:literal $(, $($fld:tt)+)?) $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { $crate::__trace_error!($msg $(, $($fld)+)?); return ::core::result::Result::Err(::core::convert::Into::into($msg)) }; };
+    
+    // Tri-Fail (Location)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> loc $msg:literal $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err(::core::convert::Into::into($crate::__located_error!($msg))) }; };
+    
+    // Tri-Fail (Box)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> box $otw:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err($crate::__box_error!($otw)) }; };
+    
+    // Tri-Into
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ~> $otw:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err(::core::convert::Into::into($otw)) }; };
+    
+    // Tri-Convert (Propagate)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -?> $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err(__tri_err) => return ::core::result::Result::Err(::core::convert::From::from(__tri_err)) }; };
+    
+    // Tri-Ok-Or
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ok_or> $($tal:tt)+) =>
+    { $crate::__expand_caption!($chk => $xpv [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)?)?, $($alt)?),*] [$($($bmo)* # $cln),*] -?> $($tal)+) };
+    
+    // Tri-Convert
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -?> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), _ => return ::core::result::Result::Err(::core::convert::From::from($otw)) }; };
+    
+    // Tri-Fail
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> $otw:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err($otw) }; };
+    
+    // Tri-Default
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ?: $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $crate::__scalar!($([] $cln),*) } else { ::core::default::Default::default() }; };
+    
+    // Tri-Default (Warn)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <warn_default $msg:literal> $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $crate::__scalar!($([] $cln),*) } else { $crate::__warn_default!($msg); ::core::default::Default::default() }; };
+    
+    // Tri-Cache
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] cache> $cch:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { let __tri_caps = $crate::__scalar!($([] $cln),*); $cch = ::core::clone::Clone::clone(&__tri_caps); __tri_caps } else { ::core::clone::Clone::clone(&$cch) }; };
+    
+    // Tri-Fall
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $crate::__scalar!($([] $cln),*) } else { ($($otw),+) }; };
+    
+    // Tri-Log
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ?!> $log:expr, $($otw:expr),+ $(,)? $(;)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([$($bmo)*] $cln),*) = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $crate::__scalar!($([] $cln),*) } else { $log; ($($otw),+) }; };
+    
+    // Tri-Return (Break)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> break $($tal:tt)*) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { break $($tal)* }; };
+    
+    // Tri-Return (Continue)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> continue $($tal:tt)*) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { continue $($tal)* }; };
+    
+    // Tri-Return
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> $otw:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return $otw }; };
+    
+    // Tri-Until (Retry Fail)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> [$($wat:pat),+] -> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = loop { match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => break $crate::__scalar!($([] $cln),*), $($wat)|+ => ::core::hint::spin_loop(), _ => return ::core::result::Result::Err($otw) } }; };
+    
+    // Tri-Until (Retry Return)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> [$($wat:pat),+] #> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = loop { match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => break $crate::__scalar!($([] $cln),*), $($wat)|+ => ::core::hint::spin_loop(), _ => return $otw } }; };
+    
+    // Tri-Until (Tracked)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] @ ($cnt:ident, $lst:ident) %> $otw:expr $(;)?) =>
     {
-        let $($($bmo)* $cln),* = {
-            let mut __loop_monitor_dont_use_this_variable_please = ($($ani),*);
+        let ($($($bmo)* $cln,)* $cnt, $lst) = {
+            let mut __tri_count = 0_usize;
+            let mut __tri_last = ::core::option::Option::None;
             loop {
-                let $($($bmo)* $cln),+ = __loop_monitor_dont_use_this_variable_please;
-                $inc;
-                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chk else { break ($($cln),*) };
-                __loop_monitor_dont_use_this_variable_please = ($($cln),*);
+                __tri_count += 1;
+                let __tri_val = match $chk {
+                    $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => break ($($cln,)* __tri_count, __tri_last),
+                    __tri_val => __tri_val,
+                };
+                __tri_last = ::core::option::Option::Some(__tri_val);
+                $otw;
             }
         };
     };
     
-    // Tri-Fail
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] -> $otw:expr $(;)?) =>
-    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::std::result::Result::Err($otw) }; };
+    // Tri-Until (Jitter)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> jitter($rng:expr, $base:expr, $fct:expr $(, $slp:expr)? $(,)?) $(;)?) =>
+    {
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = {
+            let mut __tri_attempt = 0_u32;
+            loop {
+                if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $crate::__scalar!($([] $cln),*) }
+                $crate::__jitter_sleep!(__tri_attempt, $rng, $base, $fct $(, $slp)?);
+                __tri_attempt += 1;
+            }
+        };
+    };
     
-    // Tri-Fall
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] <> $($otw:expr $(;)?),+) =>
-    { let $($($bmo)* $cln),* = if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { ($($cln),*) } else { ($($otw),+) }; };
+    // Tri-Until (Capped Fail)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cap:expr]> $rty:expr, -> $otw:expr $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_caps = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) }
+            if __tri_tries >= $cap { break ::core::option::Option::None }
+            $rty;
+            __tri_tries += 1;
+        };
+        let ::core::option::Option::Some($crate::__scalar!($([$($bmo)*] $cln),*)) = __tri_caps else { return ::core::result::Result::Err($otw) };
+    };
     
-    // Tri-Return (Break)
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] #> break $($tal:tt)*) =>
-    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { break $($tal)* }; };
+    // Tri-Until (Capped Fall)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cap:expr]> $rty:expr, <> $($otw:expr),+ $(,)? $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_caps = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) }
+            if __tri_tries >= $cap { break ::core::option::Option::None }
+            $rty;
+            __tri_tries += 1;
+        };
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = match __tri_caps { ::core::option::Option::Some(__tri_val) => __tri_val, ::core::option::Option::None => ($($otw),+) };
+    };
     
-    // Tri-Return
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] #> $otw:expr $(;)?) =>
-    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return $otw }; };
+    // Tri-Until (Label)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> $lbl:lifetime : $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = $lbl: loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $crate::__scalar!($([] $cln),*) } $otw; }; };
     
     // Tri-Until
-    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] %> $otw:expr $(;)?) =>
-    { let($($($bmo)* $cln),*) = loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break ($($cln),*) } else { $otw; } }; };
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $crate::__scalar!($([] $cln),*) } $otw; }; };
+    
+    // Tri-Map-Err
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] maperr> $map:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err(__tri_err) => return ::core::result::Result::Err(($map)(__tri_err)) }; };
+    
+    // Tri-Bool
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] bool> $(;)?) =>
+    { ::core::matches!($chk, $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+)) };
+    
+    // Tri-Panic (Unreachable)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] !> $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { ::core::unreachable!() }; };
+    
+    // Tri-Panic
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] !> $($msg:tt)+) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { ::core::panic!($($msg)+) }; };
+    
+    // Tri-Exit
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] exit> $otw:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { $crate::__exit_code!($otw) }; };
+    
+    // Tri-Flag
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] flag> $flg:expr $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { $flg = true; continue }; };
+    
+    // Tri-Option
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] opt> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) } else { ::core::option::Option::None } };
+    
+    // Tri-Ready
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ready> $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::task::Poll::Pending }; };
+    
+    // Tri-Assert
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] assert> $(;)?) =>
+    {
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk {
+            $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*),
+            ref __tri_val => ::core::panic!("assertion `{} matches {}` failed\n  value: {:?}", ::core::stringify!($chk), ::core::stringify!($xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+)), __tri_val),
+        };
+    };
+    
+    // Tri-Yield
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] yield> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([$($bmo)*] $cln),*) = loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { break $crate::__scalar!($([] $cln),*) } else { $crate::__yield_value!($otw); } }; };
+    
+    // Tri-Emit
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] emit> $($txp:ident).+ -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { if !$crate::__emit_value!($($txp).+, $crate::__scalar!($([] $cln),*)) { return ::core::result::Result::Err($otw) } } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Gather
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] gather> $err:expr $(;)?) =>
+    {
+        let $crate::__scalar!($([$($bmo)*] $cln),*) = match $chk {
+            $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*),
+            ::core::result::Result::Err(__tri_err) => { $err.push(__tri_err); continue }
+            #[allow(unreachable_patterns)]
+            _ => continue,
+        };
+    };
+    
+    // Tri-Status
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] status> ($code:expr, $msg:expr) $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err($crate::__status_error!($code, $msg)) }; };
+    
+    // Tri-Http
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] http> ($code:expr, $body:expr) $(;)?) =>
+    { let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk else { return ::core::result::Result::Err($crate::__http_response!($code, $body)) }; };
+    
+    // Tri-Future
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] future> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $crate::__ready_future!(::core::result::Result::Ok($crate::__scalar!($([] $cln),*))) } else { $crate::__ready_future!(::core::result::Result::Err($otw)) } };
+    
+    // Tri-Ok
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] =>ok $otw:expr $(;)?) =>
+    { match $chk { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => ::core::result::Result::Ok($crate::__scalar!($([] $cln),*)), _ => ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Fail)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Return)
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else #> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { return $otw } };
+    
+    // Tri-Else
+    ($chk:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else <> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chk { $otw; } };
+    
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __expand_variant {
+    // Tri-While (Count)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] >>count $cnt:ident $(, $inc:expr)? $(;)?) =>
+    {
+        let mut $cnt: usize = 0;
+        loop {
+            let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc else { break };
+            $cnt += 1;
+            $($inc;)?
+        }
+    };
+    
+    // Tri-While (Label)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt = $ani:expr),*] >> $lbl:lifetime : $inc:expr $(;)?) =>
+    {
+        {
+            let mut __loop_monitor_dont_use_this_variable_please = ($($ani),*);
+            $lbl: loop {
+                let $crate::__scalar!($([$($bmo)*] $cln),*) = __loop_monitor_dont_use_this_variable_please;
+                $inc;
+                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chc else { break $crate::__scalar!($([] $cln),*) };
+                __loop_monitor_dont_use_this_variable_please = $crate::__scalar!($([] $cln),*);
+            }
+        }
+    };
+    
     // Tri-While
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident = $ani:expr),*] >> $inc:expr $(;)?) =>
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)? = $ini:expr)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt = $ani:expr),*] >> $inc:expr $(;)?) =>
     {
         {
             let mut __loop_monitor_dont_use_this_variable_please = ($($ani),*);
             loop {
-                let ($($($bmo)* $cln),*) = __loop_monitor_dont_use_this_variable_please;
+                let $crate::__scalar!($([$($bmo)*] $cln),*) = __loop_monitor_dont_use_this_variable_please;
                 $inc;
-                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chc else { break };
-                __loop_monitor_dont_use_this_variable_please = ($($cln),*);
+                let $xpv($($($rfi)? $($mti)? $($var $(@ $grd)?)? $($alt)?),*) = $chc else { break $crate::__scalar!($([] $cln),*) };
+                __loop_monitor_dont_use_this_variable_please = $crate::__scalar!($([] $cln),*);
             }
         }
     };
     
+    // Tri-Fail (Log)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> log $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), __tri_miss => { let __tri_err = $crate::term::LogTerm::into_error(__tri_miss); $crate::__log_error!($chc, __tri_err); return ::core::result::Result::Err(__tri_err) } } };
+    
+    // Tri-Fail (Span)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> span($msg:literal $(, $($fld:tt)+)?) $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { $crate::__trace_error!($msg $(, $($fld)+)?); return ::core::result::Result::Err(::core::convert::Into::into($msg)) } };
+    
+    // Tri-Fail (Location)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> loc $msg:literal $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err(::core::convert::Into::into($crate::__located_error!($msg))) } };
+    
+    // Tri-Fail (Box)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> box $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err($crate::__box_error!($otw)) } };
+    
+    // Tri-Fail (Guard)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] -> $otw:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Fall (Else)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <else $err:ident> $otw:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err($err) => $otw } };
+    
+    // Tri-Fall (Retries)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cnt:expr]<> $($otw:expr),+ $(,)? $(;)?) =>
+    {{
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_caps = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break $crate::__scalar!($([] $cln),*) }
+            if __tri_tries >= $cnt { break ($($otw),+) }
+            __tri_tries += 1;
+        };
+        __tri_caps
+    }};
+    
+    // Tri-Map (Fall)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $($map)+ } else { ($($otw),+) } };
+    
+    // Tri-Map (Fail)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $($map)+ } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Map (Return)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> % [$($map:tt)+] #> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $($map)+ } else { return $otw } };
+    
+    // Tri-Map
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] |> $($tal:tt)+) =>
+    { $crate::__format_map! { __expand_variant [$chc => $xpv [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)?)?, $($alt)?),*] [$($($bmo)* # $cln),*]] [] $($tal)+ } };
+    
+    // Tri-Fall (Guard)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => ($($otw),+) } };
+    
+    // Tri-Return (Guard)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] if [$cnd:expr] #> $otw:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) if $cnd => $crate::__scalar!($([] $cln),*), _ => return $otw } };
+    
+    // Tri-Fail (Tap)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] tap($tap:expr) -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { let __tri_caps = $crate::__scalar!($([] $cln),*); ($tap)(&__tri_caps); __tri_caps } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Fall (Tap)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] tap($tap:expr) <> $($otw:expr $(;)?),+ $(,)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { let __tri_caps = $crate::__scalar!($([] $cln),*); ($tap)(&__tri_caps); __tri_caps } else { ($($otw),+) } };
+    
+    // Tri-Into
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ~> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err(::core::convert::Into::into($otw)) } };
+    
+    // Tri-Convert (Propagate)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -?> $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err(__tri_err) => return ::core::result::Result::Err(::core::convert::From::from(__tri_err)) } };
+    
+    // Tri-Ok-Or
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ok_or> $($tal:tt)+) =>
+    { $crate::__expand_variant!($chc => $xpv [$($($rfi)?, $($mti)?, $([$var] $(@ $grd)?)?, $($alt)?),*] [$($($bmo)* # $cln),*] -?> $($tal)+) };
+    
+    // Tri-Convert
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -?> $otw:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), _ => return ::core::result::Result::Err(::core::convert::From::from($otw)) } };
+    
     // Tri-Fail
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] -> $otw:expr $(;)?) =>
-    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { ($($cln),*) } else { return ::std::result::Result::Err($otw) } };
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Default
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ?: $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { ::core::default::Default::default() } };
+    
+    // Tri-Default (Warn)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <warn_default $msg:literal> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { $crate::__warn_default!($msg); ::core::default::Default::default() } };
+    
+    // Tri-Cache
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] cache> $cch:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { let __tri_caps = $crate::__scalar!($([] $cln),*); $cch = ::core::clone::Clone::clone(&__tri_caps); __tri_caps } else { ::core::clone::Clone::clone(&$cch) } };
     
     // Tri-Fall
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] <> $($otw:expr $(;)?),+ $(,)?) =>
-    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { ($($cln),*) } else { ($($otw),+) } };
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { ($($otw),+) } };
+    
+    // Tri-Log
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ?!> $log:expr, $($otw:expr),+ $(,)? $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { $log; ($($otw),+) } };
     
     // Tri-Return (Break)
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] #> break $($tal:tt)*) =>
-    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { ($($cln),*) } else { break $($tal)* } };
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> break $($tal:tt)*) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { break $($tal)* } };
+    
+    // Tri-Return (Continue)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> continue $($tal:tt)*) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { continue $($tal)* } };
     
     // Tri-Return
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] #> $otw:expr $(;)?) =>
-    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { ($($cln),*) } else { return $otw }; };
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] #> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return $otw }; };
+    
+    // Tri-Until (Retry Fail)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> [$($wat:pat),+] -> $otw:expr $(;)?) =>
+    { loop { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => break $crate::__scalar!($([] $cln),*), $($wat)|+ => ::core::hint::spin_loop(), _ => return ::core::result::Result::Err($otw) } } };
+    
+    // Tri-Until (Retry Return)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> [$($wat:pat),+] #> $otw:expr $(;)?) =>
+    { loop { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => break $crate::__scalar!($([] $cln),*), $($wat)|+ => ::core::hint::spin_loop(), _ => return $otw } } };
+    
+    // Tri-Until (Jitter)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> jitter($rng:expr, $base:expr, $fct:expr $(, $slp:expr)? $(,)?) $(;)?) =>
+    {{
+        let mut __tri_attempt = 0_u32;
+        loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break $crate::__scalar!($([] $cln),*) }
+            $crate::__jitter_sleep!(__tri_attempt, $rng, $base, $fct $(, $slp)?);
+            __tri_attempt += 1;
+        }
+    }};
+    
+    // Tri-Until (Capped Fail)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cap:expr]> $rty:expr, -> $otw:expr $(;)?) =>
+    {{
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_caps = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) }
+            if __tri_tries >= $cap { break ::core::option::Option::None }
+            $rty;
+            __tri_tries += 1;
+        };
+        match __tri_caps { ::core::option::Option::Some(__tri_val) => __tri_val, ::core::option::Option::None => return ::core::result::Result::Err($otw) }
+    }};
+    
+    // Tri-Until (Capped Fall)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %[$cap:expr]> $rty:expr, <> $($otw:expr),+ $(,)? $(;)?) =>
+    {{
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_caps = loop {
+            if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) }
+            if __tri_tries >= $cap { break ::core::option::Option::None }
+            $rty;
+            __tri_tries += 1;
+        };
+        match __tri_caps { ::core::option::Option::Some(__tri_val) => __tri_val, ::core::option::Option::None => ($($otw),+) }
+    }};
+    
+    // Tri-Until (Label)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> $lbl:lifetime : $otw:expr $(;)?) =>
+    { $lbl: loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break $crate::__scalar!($([] $cln),*) } $otw; } };
     
     // Tri-Until
-    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $($var:ident $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:ident),*] %> $otw:expr $(;)?) =>
-    { loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break ($($cln),*) } else { $otw; } } };
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] %> $otw:expr $(;)?) =>
+    { loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break $crate::__scalar!($([] $cln),*) } $otw; } };
+    
+    // Tri-Map-Err
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] maperr> $map:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*), ::core::result::Result::Err(__tri_err) => return ::core::result::Result::Err(($map)(__tri_err)) } };
+    
+    // Tri-Bool
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] bool> $(;)?) =>
+    { ::core::matches!($chc, $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+)) };
+    
+    // Tri-Panic (Unreachable)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] !> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { ::core::unreachable!() } };
+    
+    // Tri-Panic
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] !> $($msg:tt)+) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { ::core::panic!($($msg)+) } };
+    
+    // Tri-Exit
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] exit> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { $crate::__exit_code!($otw) } };
+    
+    // Tri-Flag
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] flag> $flg:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { $flg = true; continue } };
+    
+    // Tri-Option
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] opt> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { ::core::option::Option::Some($crate::__scalar!($([] $cln),*)) } else { ::core::option::Option::None } };
+    
+    // Tri-Ready
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] ready> $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::task::Poll::Pending } };
+    
+    // Tri-Assert
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] assert> $(;)?) =>
+    {
+        match $chc {
+            $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*),
+            ref __tri_val => ::core::panic!("assertion `{} matches {}` failed\n  value: {:?}", ::core::stringify!($chc), ::core::stringify!($xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+)), __tri_val),
+        }
+    };
+    
+    // Tri-Yield
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] yield> $otw:expr $(;)?) =>
+    { loop { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { break $crate::__scalar!($([] $cln),*) } else { $crate::__yield_value!($otw); } } };
+    
+    // Tri-Emit
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] emit> $($txp:ident).+ -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { if !$crate::__emit_value!($($txp).+, $crate::__scalar!($([] $cln),*)) { return ::core::result::Result::Err($otw) } } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Gather
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] gather> $err:expr $(;)?) =>
+    {
+        match $chc {
+            $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => $crate::__scalar!($([] $cln),*),
+            ::core::result::Result::Err(__tri_err) => { $err.push(__tri_err); continue }
+            #[allow(unreachable_patterns)]
+            _ => continue,
+        }
+    };
+    
+    // Tri-Status
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] status> ($code:expr, $msg:expr) $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err($crate::__status_error!($code, $msg)) } };
+    
+    // Tri-Http
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] http> ($code:expr, $body:expr) $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__scalar!($([] $cln),*) } else { return ::core::result::Result::Err($crate::__http_response!($code, $body)) } };
+    
+    // Tri-Future
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] future> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $crate::__ready_future!(::core::result::Result::Ok($crate::__scalar!($([] $cln),*))) } else { $crate::__ready_future!(::core::result::Result::Err($otw)) } };
+    
+    // Tri-Ok
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] =>ok $otw:expr $(;)?) =>
+    { match $chc { $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) => ::core::result::Result::Ok($crate::__scalar!($([] $cln),*)), _ => ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Fail)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else -> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Return)
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else #> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { return $otw } };
+    
+    // Tri-Else
+    ($chc:expr => $xpv:path [$($($rfi:ident)?, $($mti:ident)?, $([$var:tt] $(@ $grd:pat)?)?, $($alt:pat)?),*] [$($($bmo:ident)* # $cln:tt),*] else <> $otw:expr $(;)?) =>
+    { if let $xpv($($($rfi)* $($mti)? $($var $(@ $grd)?)? $($alt)?),+) = $chc { $otw; } };
+    
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __expand_path {
+    // Tri-While (Count)
+    ($chc:expr => $xpv:path [] >>count $cnt:ident $(, $inc:expr)? $(;)?) =>
+    {
+        let mut $cnt: usize = 0;
+        loop {
+            let $xpv = $chc else { break };
+            $cnt += 1;
+            $($inc;)?
+        }
+    };
+    
+    // Tri-While (Label)
+    ($chc:expr => $xpv:path [] >> $lbl:lifetime : $inc:expr $(;)?) =>
+    { $lbl: loop { $inc; let $xpv = $chc else { break }; } };
+    
     // Tri-While
     ($chc:expr => $xpv:path [] >> $inc:expr $(;)?) =>
     { loop { $inc; let $xpv = $chc else { break }; } };
     
+    // Tri-Fail (Span)
+    ($chc:expr => $xpv:path [] -> span($msg:literal $(, $($fld:tt)+)?) $(;)?) =>
+    { let $xpv = $chc else { $crate::__trace_error!($msg $(, $($fld)+)?); return ::core::result::Result::Err(::core::convert::Into::into($msg)) }; };
+    
+    // Tri-Fail (Location)
+    ($chc:expr => $xpv:path [] -> loc $msg:literal $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err(::core::convert::Into::into($crate::__located_error!($msg))) }; };
+    
+    // Tri-Fail (Box)
+    ($chc:expr => $xpv:path [] -> box $otw:expr $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err($crate::__box_error!($otw)) }; };
+    
+    // Tri-Into
+    ($chc:expr => $xpv:path [] ~> $otw:expr $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err(::core::convert::Into::into($otw)) }; };
+    
+    // Tri-Ok-Or
+    ($chc:expr => $xpv:path [] ok_or> $($tal:tt)+) =>
+    { $crate::__expand_path!($chc => $xpv [] -?> $($tal)+) };
+    
+    // Tri-Convert
+    ($chc:expr => $xpv:path [] -?> $otw:expr $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err(::core::convert::From::from($otw)) }; };
+    
     // Tri-Fail
     ($chc:expr => $xpv:path [] -> $otw:expr $(;)?) =>
-    { let $xpv = $chc else { return ::std::result::Result::Err($otw) }; };
+    { let $xpv = $chc else { return ::core::result::Result::Err($otw) }; };
     
     // Tri-Fall
-    ($chc:expr => $xpv:path [] <> $otw:expr $(;)?) =>
+    ($chc:expr => $xpv:path [] <> $otw:expr $(,)? $(;)?) =>
     { match $chc { $xpv => (), _ => { $otw; } } };
     
+    // Tri-Fall (Tuple)
+    ($chc:expr => $xpv:path [] <> $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val @ $xpv => __tri_val, _ => ($($otw),+) } };
+    
+    // Tri-Log
+    ($chc:expr => $xpv:path [] ?!> $log:expr, $otw:expr $(,)? $(;)?) =>
+    { match $chc { $xpv => (), _ => { $log; $otw; } } };
+    
+    // Tri-Log (Tuple)
+    ($chc:expr => $xpv:path [] ?!> $log:expr, $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val @ $xpv => __tri_val, _ => { $log; ($($otw),+) } } };
+    
     // Tri-Return (Break)
     ($chc:expr => $xpv:path [] #> break $($tal:tt)*) =>
     { let $xpv = $chc else { break $($tal)* }; };
     
+    // Tri-Return (Continue)
+    ($chc:expr => $xpv:path [] #> continue $($tal:tt)*) =>
+    { let $xpv = $chc else { continue $($tal)* }; };
+    
     // Tri-Return
     ($chc:expr => $xpv:path [] #> $otw:expr $(;)?) =>
     { let $xpv = $chc else { return $otw }; };
     
+    // Tri-Until (Capped Fail)
+    ($chc:expr => $xpv:path [] %[$cap:expr]> $rty:expr, -> $otw:expr $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_done = loop {
+            if let $xpv = $chc { break true }
+            if __tri_tries >= $cap { break false }
+            $rty;
+            __tri_tries += 1;
+        };
+        if !__tri_done { return ::core::result::Result::Err($otw) }
+    };
+    
+    // Tri-Until (Capped Fall)
+    ($chc:expr => $xpv:path [] %[$cap:expr]> $rty:expr, <> $otw:expr $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_done = loop {
+            if let $xpv = $chc { break true }
+            if __tri_tries >= $cap { break false }
+            $rty;
+            __tri_tries += 1;
+        };
+        if !__tri_done { $otw; }
+    };
+    
+    // Tri-Until (Label)
+    ($chc:expr => $xpv:path [] %> $lbl:lifetime : $otw:expr $(;)?) =>
+    { $lbl: loop { if let $xpv = $chc { break } $otw; } };
+    
     // Tri-Until
     ($chc:expr => $xpv:path [] %> $otw:expr $(;)?) =>
-    { loop { if let $xpv = $chc { break } else { $otw; } } };
+    { loop { if let $xpv = $chc { break } $otw; } };
+    
+    // Tri-Bool
+    ($chc:expr => $xpv:path [] bool> $(;)?) =>
+    { ::core::matches!($chc, $xpv) };
+    
+    // Tri-Panic (Unreachable)
+    ($chc:expr => $xpv:path [] !> $(;)?) =>
+    { let $xpv = $chc else { ::core::unreachable!() }; };
+    
+    // Tri-Panic
+    ($chc:expr => $xpv:path [] !> $($msg:tt)+) =>
+    { let $xpv = $chc else { ::core::panic!($($msg)+) }; };
+    
+    // Tri-Exit
+    ($chc:expr => $xpv:path [] exit> $otw:expr $(;)?) =>
+    { let $xpv = $chc else { $crate::__exit_code!($otw) }; };
+    
+    // Tri-Flag
+    ($chc:expr => $xpv:path [] flag> $flg:expr $(;)?) =>
+    { let $xpv = $chc else { $flg = true; continue }; };
+    
+    // Tri-Option
+    ($chc:expr => $xpv:path [] opt> $(;)?) =>
+    { match $chc { __tri_val @ $xpv => ::core::option::Option::Some(__tri_val), _ => ::core::option::Option::None } };
+    
+    // Tri-Assert
+    ($chc:expr => $xpv:path [] assert> $(;)?) =>
+    {
+        match $chc {
+            $xpv => (),
+            ref __tri_val => ::core::panic!("assertion `{} matches {}` failed\n  value: {:?}", ::core::stringify!($chc), ::core::stringify!($xpv), __tri_val),
+        }
+    };
+    
+    // Tri-Gather
+    ($chc:expr => $xpv:path [] gather> $err:expr $(;)?) =>
+    {
+        let __tri_val = $chc;
+        let $xpv = __tri_val else { if let ::core::result::Result::Err(__tri_err) = __tri_val { $err.push(__tri_err) } continue };
+    };
+    
+    // Tri-Status
+    ($chc:expr => $xpv:path [] status> ($code:expr, $msg:expr) $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err($crate::__status_error!($code, $msg)) }; };
+    
+    // Tri-Http
+    ($chc:expr => $xpv:path [] http> ($code:expr, $body:expr) $(;)?) =>
+    { let $xpv = $chc else { return ::core::result::Result::Err($crate::__http_response!($code, $body)) }; };
+    
+    // Tri-Future
+    ($chc:expr => $xpv:path [] future> $otw:expr $(;)?) =>
+    { match $chc { __tri_val @ $xpv => $crate::__ready_future!(::core::result::Result::Ok(__tri_val)), _ => $crate::__ready_future!(::core::result::Result::Err($otw)) } };
+    
+    // Tri-Ok
+    ($chc:expr => $xpv:path [] =>ok $otw:expr $(;)?) =>
+    { match $chc { __tri_val @ $xpv => ::core::result::Result::Ok(__tri_val), _ => ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Fail)
+    ($chc:expr => $xpv:path [] else -> $otw:expr $(;)?) =>
+    { if let $xpv = $chc { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Return)
+    ($chc:expr => $xpv:path [] else #> $otw:expr $(;)?) =>
+    { if let $xpv = $chc { return $otw } };
+    
+    // Tri-Else
+    ($chc:expr => $xpv:path [] else <> $otw:expr $(;)?) =>
+    { if let $xpv = $chc { $otw; } };
+    
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __expand_rule {
+    // Tri-While (Count)
+    ($chc:expr => [$($rle:pat),+] >>count $cnt:ident $(, $inc:expr)? $(;)?) =>
+    {
+        let mut $cnt: usize = 0;
+        loop {
+            let $crate::__scalar!($([] $rle),+) = $chc else { break };
+            $cnt += 1;
+            $($inc;)?
+        }
+    };
+    
+    // Tri-While (Label)
+    ($chc:expr => [$($rle:pat),+] >> $lbl:lifetime : $inc:expr $(;)?) =>
+    { $lbl: loop { $inc; let $crate::__scalar!($([] $rle),+) = $chc else { break }; } };
+    
     // Tri-While
     ($chc:expr => [$($rle:pat),+] >> $inc:expr $(;)?) =>
-    { loop { $inc; let ($($rle),+) = $chc else { break }; } };
+    { loop { $inc; let $crate::__scalar!($([] $rle),+) = $chc else { break }; } };
+    
+    // Tri-Fail (Guard)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] -> $otw:expr $(;)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err($otw) }; if !($cnd) { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Fall (Guard Return)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] <> return $($tal:tt)*) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([] $rle),+) = $chc else { return $($tal)* }; if !($cnd) { return $($tal)* } };
+    
+    // Tri-Fall (Guard Break)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] <> break $($tal:tt)*) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([] $rle),+) = $chc else { break $($tal)* }; if !($cnd) { break $($tal)* } };
+    
+    // Tri-Fall (Guard Continue)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] <> continue $($tal:tt)*) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([] $rle),+) = $chc else { continue $($tal)* }; if !($cnd) { continue $($tal)* } };
+    
+    // Tri-Fall (Guard)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] <> $otw:expr $(,)? $(;)?) =>
+    { match $chc { $crate::__scalar!($([] $rle),+) if $cnd => (), _ => { $otw; } } };
+    
+    // Tri-Return (Guard)
+    ($chc:expr => [$($rle:pat),+] if [$cnd:expr] #> $otw:expr $(;)?) =>
+    { #[allow(irrefutable_let_patterns)] let $crate::__scalar!($([] $rle),+) = $chc else { return $otw }; if !($cnd) { return $otw } };
+    
+    // Guard
+    ($chc:expr => [$($rle:pat),+] if $($tal:tt)+) =>
+    { $crate::__format_guard! { __expand_rule [$chc => [$($rle),+]] [] $($tal)+ } };
+    
+    // Tri-Fail (Span)
+    ($chc:expr => [$($rle:pat),+] -> span($msg:literal $(, $($fld:tt)+)?) $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { $crate::__trace_error!($msg $(, $($fld)+)?); return ::core::result::Result::Err(::core::convert::Into::into($msg)) }; };
+    
+    // Tri-Fail (Location)
+    ($chc:expr => [$($rle:pat),+] -> loc $msg:literal $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err(::core::convert::Into::into($crate::__located_error!($msg))) }; };
+    
+    // Tri-Fail (Box)
+    ($chc:expr => [$($rle:pat),+] -> box $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err($crate::__box_error!($otw)) }; };
+    
+    // Tri-Into
+    ($chc:expr => [$($rle:pat),+] ~> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err(::core::convert::Into::into($otw)) }; };
+    
+    // Tri-Ok-Or
+    ($chc:expr => [$($rle:pat),+] ok_or> $($tal:tt)+) =>
+    { $crate::__expand_rule!($chc => [$($rle),+] -?> $($tal)+) };
+    
+    // Tri-Convert
+    ($chc:expr => [$($rle:pat),+] -?> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err(::core::convert::From::from($otw)) }; };
     
     // Tri-Fail
     ($chc:expr => [$($rle:pat),+] -> $otw:expr $(;)?) =>
-    { let ($($rle),+) = $chc else { return ::std::result::Result::Err($otw) }; };
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err($otw) }; };
+    
+    // Tri-Fall (Return)
+    ($chc:expr => [$($rle:pat),+] <> return $($tal:tt)*) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return $($tal)* }; };
+    
+    // Tri-Fall (Break)
+    ($chc:expr => [$($rle:pat),+] <> break $($tal:tt)*) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { break $($tal)* }; };
+    
+    // Tri-Fall (Continue)
+    ($chc:expr => [$($rle:pat),+] <> continue $($tal:tt)*) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { continue $($tal)* }; };
     
     // Tri-Fall
-    ($chc:expr => [$($rle:pat),+] <> $otw:expr $(;)?) =>
-    { match $chc { ($($rle),+) => (), _ => { $otw } } };
+    ($chc:expr => [$($rle:pat),+] <> $otw:expr $(,)? $(;)?) =>
+    { match $chc { $crate::__scalar!($([] $rle),+) => (), _ => { $otw; } } };
+    
+    // Tri-Fall (Tuple)
+    ($chc:expr => [$($rle:pat),+] <> $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val @ $crate::__scalar!($([] $rle),+) => __tri_val, _ => ($($otw),+) } };
+    
+    // Tri-Log
+    ($chc:expr => [$($rle:pat),+] ?!> $log:expr, $otw:expr $(,)? $(;)?) =>
+    { match $chc { $crate::__scalar!($([] $rle),+) => (), _ => { $log; $otw; } } };
+    
+    // Tri-Log (Tuple)
+    ($chc:expr => [$($rle:pat),+] ?!> $log:expr, $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val @ $crate::__scalar!($([] $rle),+) => __tri_val, _ => { $log; ($($otw),+) } } };
     
     // Tri-Return (Break)
     ($chc:expr => [$($rle:pat),+] #> break $($tal:tt)*) =>
-    { let ($($rle),+) = $chc else { break $($tal)* }; };
+    { let $crate::__scalar!($([] $rle),+) = $chc else { break $($tal)* }; };
+    
+    // Tri-Return (Continue)
+    ($chc:expr => [$($rle:pat),+] #> continue $($tal:tt)*) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { continue $($tal)* }; };
     
     // Tri-Return
     ($chc:expr => [$($rle:pat),+] #> $otw:expr $(;)?) =>
-    { let ($($rle),+) = $chc else { return $otw }; };
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return $otw }; };
+    
+    // Tri-Until (Capped Fail)
+    ($chc:expr => [$($rle:pat),+] %[$cap:expr]> $rty:expr, -> $otw:expr $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_done = loop {
+            if let $crate::__scalar!($([] $rle),+) = $chc { break true }
+            if __tri_tries >= $cap { break false }
+            $rty;
+            __tri_tries += 1;
+        };
+        if !__tri_done { return ::core::result::Result::Err($otw) }
+    };
+    
+    // Tri-Until (Capped Fall)
+    ($chc:expr => [$($rle:pat),+] %[$cap:expr]> $rty:expr, <> $otw:expr $(;)?) =>
+    {
+        let mut __tri_tries: usize = 0;
+        #[allow(unused_comparisons)]
+        let __tri_done = loop {
+            if let $crate::__scalar!($([] $rle),+) = $chc { break true }
+            if __tri_tries >= $cap { break false }
+            $rty;
+            __tri_tries += 1;
+        };
+        if !__tri_done { $otw; }
+    };
+    
+    // Tri-Until (Label)
+    ($chc:expr => [$($rle:pat),+] %> $lbl:lifetime : $otw:expr $(;)?) =>
+    { $lbl: loop { let $crate::__scalar!($([] $rle),+) = $chc else { $otw; continue }; break } };
     
     // Tri-Until
     ($chc:expr => [$($rle:pat),+] %> $otw:expr $(;)?) =>
-    { loop { if let $($rle),+ = $chc { break } else { $otw } } };
+    { loop { let $crate::__scalar!($([] $rle),+) = $chc else { $otw; continue }; break } };
+    
+    // Tri-Bool
+    ($chc:expr => [$($rle:pat),+] bool> $(;)?) =>
+    { match $chc { $crate::__scalar!($([] $rle),+) => true, _ => false } };
+    
+    // Tri-Panic (Unreachable)
+    ($chc:expr => [$($rle:pat),+] !> $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { ::core::unreachable!() }; };
+    
+    // Tri-Panic
+    ($chc:expr => [$($rle:pat),+] !> $($msg:tt)+) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { ::core::panic!($($msg)+) }; };
+    
+    // Tri-Exit
+    ($chc:expr => [$($rle:pat),+] exit> $otw:expr $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { $crate::__exit_code!($otw) }; };
+    
+    // Tri-Flag
+    ($chc:expr => [$($rle:pat),+] flag> $flg:expr $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { $flg = true; continue }; };
+    
+    // Tri-Option
+    ($chc:expr => [$($rle:pat),+] opt> $(;)?) =>
+    { match $chc { __tri_val @ $crate::__scalar!($([] $rle),+) => ::core::option::Option::Some(__tri_val), _ => ::core::option::Option::None } };
+    
+    // Tri-Ready
+    ($chc:expr => [$($rle:pat),+] ready> $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::task::Poll::Pending }; };
+    
+    // Tri-Assert
+    ($chc:expr => [$($rle:pat),+] assert> $(;)?) =>
+    {
+        let __tri_val = $chc;
+        let $crate::__scalar!($([] $rle),+) = __tri_val else {
+            ::core::panic!("assertion `{} matches {}` failed\n  value: {:?}", ::core::stringify!($chc), ::core::stringify!($($rle),+), __tri_val)
+        };
+    };
+    
+    // Tri-Yield
+    ($chc:expr => [$($rle:pat),+] yield> $otw:expr $(;)?) =>
+    { loop { if let $crate::__scalar!($([] $rle),+) = $chc { break } else { $crate::__yield_value!($otw); } } };
+    
+    // Tri-Gather
+    ($chc:expr => [$($rle:pat),+] gather> $err:expr $(;)?) =>
+    {
+        let __tri_val = $chc;
+        let $crate::__scalar!($([] $rle),+) = __tri_val else { if let ::core::result::Result::Err(__tri_err) = __tri_val { $err.push(__tri_err) } continue };
+    };
+    
+    // Tri-Status
+    ($chc:expr => [$($rle:pat),+] status> ($code:expr, $msg:expr) $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err($crate::__status_error!($code, $msg)) }; };
+    
+    // Tri-Http
+    ($chc:expr => [$($rle:pat),+] http> ($code:expr, $body:expr) $(;)?) =>
+    { let $crate::__scalar!($([] $rle),+) = $chc else { return ::core::result::Result::Err($crate::__http_response!($code, $body)) }; };
+    
+    // Tri-Future
+    ($chc:expr => [$($rle:pat),+] future> $otw:expr $(;)?) =>
+    { match $chc { __tri_val @ $crate::__scalar!($([] $rle),+) => $crate::__ready_future!(::core::result::Result::Ok(__tri_val)), _ => $crate::__ready_future!(::core::result::Result::Err($otw)) } };
+    
+    // Tri-Ok
+    ($chc:expr => [$($rle:pat),+] =>ok $otw:expr $(;)?) =>
+    { match $chc { __tri_val @ $crate::__scalar!($([] $rle),+) => ::core::result::Result::Ok(__tri_val), _ => ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Fail)
+    ($chc:expr => [$($rle:pat),+] else -> $otw:expr $(;)?) =>
+    { if let $crate::__scalar!($([] $rle),+) = $chc { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Else (Return)
+    ($chc:expr => [$($rle:pat),+] else #> $otw:expr $(;)?) =>
+    { if let $crate::__scalar!($([] $rle),+) = $chc { return $otw } };
+    
+    // Tri-Else
+    ($chc:expr => [$($rle:pat),+] else <> $otw:expr $(;)?) =>
+    { if let $crate::__scalar!($([] $rle),+) = $chc { $otw; } };
+    
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_range {
+    // Tri-Fall
+    ($chc:expr => [$rng:expr] <> $otw:expr $(,)? $(;)?) =>
+    { $crate::tri!(::core::ops::RangeBounds::contains(&$rng, &$chc) => [true] <> $otw) };
+    
+    // Tri-Fall (Tuple)
+    ($chc:expr => [$rng:expr] <> $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val if ::core::ops::RangeBounds::contains(&$rng, &__tri_val) => __tri_val, _ => ($($otw),+) } };
+    
+    // Tri-Log
+    ($chc:expr => [$rng:expr] ?!> $log:expr, $otw:expr $(,)? $(;)?) =>
+    { $crate::tri!(::core::ops::RangeBounds::contains(&$rng, &$chc) => [true] ?!> $log, $otw) };
+    
+    // Tri-Log (Tuple)
+    ($chc:expr => [$rng:expr] ?!> $log:expr, $($otw:expr),+ $(,)? $(;)?) =>
+    { match $chc { __tri_val if ::core::ops::RangeBounds::contains(&$rng, &__tri_val) => __tri_val, _ => { $log; ($($otw),+) } } };
+    
+    // Tri-Option
+    ($chc:expr => [$rng:expr] opt> $(;)?) =>
+    { match $chc { __tri_val if ::core::ops::RangeBounds::contains(&$rng, &__tri_val) => ::core::option::Option::Some(__tri_val), _ => ::core::option::Option::None } };
+    
+    // Tri-Assert
+    ($chc:expr => [$rng:expr] assert> $(;)?) =>
+    {
+        let __tri_val = $chc;
+        if !::core::ops::RangeBounds::contains(&$rng, &__tri_val) {
+            ::core::panic!("assertion `{} matches {}` failed\n  value: {:?}", ::core::stringify!($chc), ::core::stringify!($rng), __tri_val)
+        }
+    };
+    
+    // Tri-Future
+    ($chc:expr => [$rng:expr] future> $otw:expr $(;)?) =>
+    { match $chc { __tri_val if ::core::ops::RangeBounds::contains(&$rng, &__tri_val) => $crate::__ready_future!(::core::result::Result::Ok(__tri_val)), _ => $crate::__ready_future!(::core::result::Result::Err($otw)) } };
+    
+    // Tri-Ok
+    ($chc:expr => [$rng:expr] =>ok $otw:expr $(;)?) =>
+    { match $chc { __tri_val if ::core::ops::RangeBounds::contains(&$rng, &__tri_val) => ::core::result::Result::Ok(__tri_val), _ => ::core::result::Result::Err($otw) } };
+    
+    // Note - The Remaining Operators Don't Give The Value Back, So Only The Check Is Needed
+    // Check
+    ($chc:expr => [$rng:expr] $($tal:tt)+) =>
+    { $crate::tri!(::core::ops::RangeBounds::contains(&$rng, &$chc) => [true] $($tal)+) };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __expand_struct {
+    // Tri-Fail
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] -> $otw:expr $(;)?) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { return ::core::result::Result::Err($otw) } };
+    
+    // Tri-Return
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] #> $otw:expr $(;)?) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { return $otw } };
+    
+    // Tri-Until
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] %> $otw:expr $(;)?) =>
+    { loop { if let $xpv { $($fld)* } = $chc { break $crate::__scalar!($([] $bnd),*) } $otw; } };
+    
+    // Tri-Bool
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] bool> $(;)?) =>
+    { ::core::matches!($chc, $xpv { $($fld)* }) };
+    
+    // Tri-Option
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] opt> $(;)?) =>
+    { if let $xpv { $($fld)* } = $chc { ::core::option::Option::Some($crate::__scalar!($([] $bnd),*)) } else { ::core::option::Option::None } };
+    
+    // Tri-Panic (Unreachable)
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] !> $(;)?) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { ::core::unreachable!() } };
+    
+    // Tri-Panic
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] !> $($msg:tt)+) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { ::core::panic!($($msg)+) } };
+    
+    // Tri-Fall
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] <> $($otw:expr $(;)?),+ $(,)?) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { ($($otw),+) } };
+    
+    // Tri-Log
+    ($chc:expr => $xpv:path { $($fld:tt)* } [$($bnd:ident)*] ?!> $log:expr, $($otw:expr),+ $(,)?) =>
+    { if let $xpv { $($fld)* } = $chc { $crate::__scalar!($([] $bnd),*) } else { $log; ($($otw),+) } };
+    
+    // Malformed
+    ($($tal:tt)*) =>
+    { $crate::__malformed!() };
 }
@@ -0,0 +1,80 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::sync::Mutex;
+use tokio::task;
+use tri_ton::tri;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn retry_across_tasks() {
+    let server = Arc::new(Mutex::new(Server { pending: 3, pages: VecDeque::from([4, 5, 6]) }));
+    
+    let page = task::spawn(first_page(server.clone())).await.unwrap();
+    assert_eq!(page, 4);
+    
+    let (total, last) = task::spawn(sum_pages(server.clone())).await.unwrap();
+    assert_eq!((total, last), (11, 6));
+}
+
+#[tokio::test]
+async fn await_in_step() {
+    let server = Mutex::new(Server { pending: 2, pages: VecDeque::from([1, 2]) });
+    let mut yields = 0;
+    
+    // Tri-Until (Variant)
+    let page = tri!(server.lock().await.next() => Some(page) %> { yields += 1; task::yield_now().await });
+    assert_eq!((page, yields), (1, 2));
+    
+    // Tri-While (Variant)
+    let last = tri!(server.lock().await.next() => Some(last = 0) >> task::yield_now().await);
+    assert_eq!(last, 2);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn release_guard_before_step() {
+    let server = Arc::new(StdMutex::new(Server { pending: 2, pages: VecDeque::from([7]) }));
+    let page = task::spawn(refill_page(server)).await.unwrap();
+    assert_eq!(page, 9);
+}
+
+async fn first_page(server: Arc<Mutex<Server>>) -> u8 {
+    // Tri-Until
+    tri!(server.lock().await.next() => Some[page] %> task::yield_now().await);
+    page
+}
+
+async fn sum_pages(server: Arc<Mutex<Server>>) -> (u8, u8) {
+    let mut total = 0;
+    
+    // Tri-While
+    tri!(server.lock().await.next() => Some[page = 0] >> {
+        total += page;
+        task::yield_now().await;
+    });
+    
+    (total, page)
+}
+
+async fn refill_page(server: Arc<StdMutex<Server>>) -> u8 {
+    // Tri-Until (Variant)
+    tri!(server.lock().unwrap().next() => Some(page @ 9) %> {
+        server.lock().unwrap().pages.push_front(9);
+        task::yield_now().await;
+    })
+}
+
+struct Server {
+    pending: u8,
+    pages: VecDeque<u8>,
+}
+
+impl Server {
+    fn next(&mut self) -> Option<u8> {
+        if self.pending > 0 {
+            self.pending -= 1;
+            return None
+        }
+        
+        self.pages.pop_front()
+    }
+}
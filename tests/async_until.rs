@@ -0,0 +1,96 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+use tri_ton::tri;
+
+#[test]
+fn retry_awaited_fetch() {
+    let mut client = Client { calls: 0 };
+    
+    let body = block_on(fetch_first(&mut client, 0)).unwrap();
+    assert_eq!(body, "page 3");
+    assert_eq!(client.calls, 4);
+    
+    let error = block_on(fetch_first(&mut client, 9)).unwrap_err();
+    assert_eq!(error, "offline");
+}
+
+#[test]
+fn retry_awaited_variant() {
+    let mut client = Client { calls: 0 };
+    
+    let (page, body) = block_on(async {
+        let mut url = 1;
+        
+        // Tri-Until (Variant)
+        let body = tri!(client.fetch(url).await => Ok(body) %> { url = next_url(url); pause().await });
+        (url, body)
+    });
+    
+    assert_eq!((page, body), (3, "page 3"));
+}
+
+async fn fetch_first(client: &mut Client, mut url: u8) -> Result<&'static str, &'static str> {
+    // Tri-Until
+    tri!(client.status(url).await? => Response::Found[body] %> {
+        url = next_url(url);
+        pause().await;
+    });
+    
+    Ok(body)
+}
+
+struct Client {
+    calls: u8,
+}
+
+impl Client {
+    async fn fetch(&mut self, url: u8) -> Result<&'static str, u8> {
+        self.calls += 1;
+        pause().await;
+        
+        match url {
+            3 => Ok("page 3"),
+            _ => Err(url),
+        }
+    }
+    
+    async fn status(&mut self, url: u8) -> Result<Response, &'static str> {
+        match (url, self.fetch(url).await) {
+            (9.., _) => Err("offline"),
+            (_, Ok(body)) => Ok(Response::Found(body)),
+            (_, Err(_)) => Ok(Response::Missing),
+        }
+    }
+}
+
+enum Response {
+    Found(&'static str),
+    Missing,
+}
+
+fn next_url(url: u8) -> u8 {
+    url + 1
+}
+
+async fn pause() {
+    let mut yielded = false;
+    
+    std::future::poll_fn(|cx| {
+        if yielded { return Poll::Ready(()) }
+        
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }).await
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) { return output }
+    }
+}
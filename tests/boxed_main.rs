@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::fmt;
+
+use tri_ton::tri;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let port = parse_port(&["serve", "8080"])?;
+    assert_eq!(port, 8080);
+    
+    let missing = parse_port(&["serve"]).unwrap_err();
+    assert_eq!(missing.to_string(), "missing port");
+    
+    let invalid = parse_port(&["serve", "eighty"]).unwrap_err();
+    assert_eq!(invalid.to_string(), "invalid port");
+    
+    let mode = tri!(parse_mode("fast") => Some(mode) -> Box::new(UsageError));
+    assert_eq!(mode, Mode::Fast);
+    
+    let unknown = check_mode("slow").unwrap_err();
+    assert!(unknown.is::<UsageError>());
+    
+    Ok(())
+}
+
+fn parse_port(args: &[&str]) -> Result<u16, Box<dyn Error>> {
+    tri!(args.get(1) => Some[port] ->box "missing port");
+    tri!(port.parse::<u16>() => Ok[port] ->box String::from("invalid port"));
+    Ok(port)
+}
+
+fn check_mode(mode: &str) -> Result<Mode, Box<dyn Error>> {
+    tri!(parse_mode(mode) => Some[mode] ->box UsageError);
+    Ok(mode)
+}
+
+fn parse_mode(mode: &str) -> Option<Mode> {
+    tri!(mode => ["fast"] #> None);
+    Some(Mode::Fast)
+}
+
+#[derive(Debug, PartialEq)]
+enum Mode {
+    Fast,
+}
+
+#[derive(Debug)]
+struct UsageError;
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("unknown mode")
+    }
+}
+
+impl Error for UsageError {}
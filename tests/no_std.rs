@@ -0,0 +1,16 @@
+#![no_std]
+
+use tri_ton::tri;
+
+#[test]
+fn fail_without_std() {
+    assert_eq!(first_if_even(&[4, 3]), Ok(4));
+    assert_eq!(first_if_even(&[]), Err("empty"));
+    assert_eq!(first_if_even(&[5]), Err("odd"));
+}
+
+fn first_if_even(items: &[u8]) -> Result<u8, &'static str> {
+    let first = tri!(items.first() => Some(first) -> "empty");
+    tri!(first % 2 => [0] -> "odd");
+    Ok(*first)
+}
@@ -0,0 +1,5 @@
+#[test]
+fn malformed_expressions() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use tri_ton::tri;
+
+fn port(item: Option<u16>) -> Result<u16, u8> {
+    let log = 7;
+    tri!(item => Some[value] -> log);
+    Ok(value)
+}
+
+fn main() {
+    let _ = port(Some(80));
+}
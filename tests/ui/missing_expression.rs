@@ -0,0 +1,6 @@
+use tri_ton::tri;
+
+fn main() {
+    let port = Some(8080);
+    tri!(port => Some[value] <>);
+}
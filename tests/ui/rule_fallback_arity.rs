@@ -0,0 +1,6 @@
+use tri_ton::tri;
+
+fn main() {
+    let pair = (1u8, 2u8);
+    let _ = tri!(pair => [(1..=9, _)] <> 0, 0, 0);
+}
@@ -0,0 +1,12 @@
+use tri_ton::tri;
+
+enum Power {
+    On,
+}
+
+use Power::*;
+
+fn main() {
+    let power = On;
+    tri!(power => !Onn);
+}
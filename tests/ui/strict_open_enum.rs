@@ -0,0 +1,11 @@
+use tri_ton::tri;
+
+enum Light {
+    On,
+    Off,
+}
+
+fn main() {
+    let light = Light::Off;
+    tri!(light => !Light::On);
+}
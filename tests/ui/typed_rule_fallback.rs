@@ -0,0 +1,7 @@
+use tri_ton::tri;
+
+fn main() {
+    let offset = 40;
+    tri!(offset + 2 => [n: i64] <> "zero");
+    tri!(offset + 2 => [m: i64] -> "not a fallback");
+}
@@ -0,0 +1,6 @@
+use tri_ton::tri;
+
+fn main() {
+    let pair = (1, 2);
+    tri!(pair => [(1, _)] => return);
+}
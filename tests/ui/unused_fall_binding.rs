@@ -0,0 +1,8 @@
+#![deny(unused_variables)]
+
+use tri_ton::tri;
+
+fn main() {
+    let port: Option<u16> = None;
+    tri!(port => Some[value] <> 8080);
+}
@@ -0,0 +1,48 @@
+use tri_ton::tri;
+
+#[test]
+fn warn_and_default() {
+    #[cfg(feature = "log")]
+    capture::install();
+    
+    let config = Config { timeout: Some(30), retries: None };
+    
+    tri!(config.timeout => Some[timeout: u64] <warn_default "using default timeout">);
+    tri!(config.retries => Some[retries: u8] <warn_default "using default retries">);
+    assert_eq!((timeout, retries), (30, 0));
+    
+    let limit = tri!(config.retries => Some(limit) <warn_default "using default limit">);
+    assert_eq!(limit, 0);
+    
+    #[cfg(feature = "log")]
+    assert_eq!(capture::records(), ["WARN using default retries", "WARN using default limit"]);
+}
+
+struct Config {
+    timeout: Option<u64>,
+    retries: Option<u8>,
+}
+
+#[cfg(feature = "log")]
+mod capture {
+    use std::sync::Mutex;
+    
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+    
+    struct Capture;
+    
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool { true }
+        fn log(&self, record: &log::Record) { RECORDS.lock().unwrap().push(format!("{} {}", record.level(), record.args())) }
+        fn flush(&self) {}
+    }
+    
+    pub fn install() {
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+    }
+    
+    pub fn records() -> Vec<String> {
+        RECORDS.lock().unwrap().clone()
+    }
+}